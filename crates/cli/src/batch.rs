@@ -0,0 +1,267 @@
+//! Charts for many birth records at once, read from a CSV or JSONL file.
+//!
+//! Records are charted on a pool of worker threads, one per core, and
+//! reported in input order. Only the Swiss Ephemeris calls inside `hd_core`
+//! take turns; parsing, zone and location lookup and chart analysis run
+//! concurrently. A record that cannot be read or charted is reported in its
+//! place and the rest of the batch goes on.
+
+use hd_core::{types, ChartAnalysis, ChartDocument, ChartOptions, DstPolicy, Gazetteer};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::birth::{Birth, DEFAULT_LOCATION};
+
+/// One birth record of the input
+#[derive(Debug, Deserialize)]
+pub struct Record {
+    #[serde(default)]
+    pub name: String,
+    pub date: String,
+    pub time: String,
+    /// IANA zone or UTC offset; takes precedence over the location's zone
+    #[serde(default, alias = "timezone")]
+    pub tz: Option<String>,
+    #[serde(default, alias = "place")]
+    pub location: Option<String>,
+}
+
+/// A record with its line number in the input, or why it could not be read
+pub struct Row {
+    pub line: usize,
+    pub record: Result<Record, String>,
+}
+
+/// Result for one row, in input order
+pub struct Outcome {
+    pub line: usize,
+    pub name: String,
+    pub chart: Result<(Birth, ChartAnalysis), String>,
+}
+
+/// Read the records of a batch file. JSONL has one JSON object per line;
+/// CSV needs a header row naming at least the `date` and `time` columns,
+/// and may have `name`, `tz` and `location` columns. Blank lines are skipped.
+pub fn read_records(text: &str, jsonl: bool) -> Result<Vec<Row>, String> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    if jsonl {
+        return Ok(text.lines().enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| Row { line: i + 1, record: serde_json::from_str(l).map_err(|e| e.to_string()) })
+            .collect());
+    }
+
+    let mut rows = csv_rows(text).into_iter();
+    let Some((_, header)) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let column = |names: &[&str]| header.iter().position(|h| names.iter().any(|n| h.trim().eq_ignore_ascii_case(n)));
+    let (Some(date), Some(time)) = (column(&["date"]), column(&["time"])) else {
+        return Err("the CSV header must name the date and time columns".to_string());
+    };
+    let (name, tz, location) = (column(&["name"]), column(&["tz", "timezone"]), column(&["location", "place"]));
+
+    Ok(rows.map(|(line, fields)| {
+        let field = |i: Option<usize>| i.and_then(|i| fields.get(i)).map(|f| f.trim().to_string()).filter(|f| !f.is_empty());
+        let record = match (field(Some(date)), field(Some(time))) {
+            (Some(date), Some(time)) => Ok(Record {
+                name: field(name).unwrap_or_default(),
+                date,
+                time,
+                tz: field(tz),
+                location: field(location),
+            }),
+            _ => Err("missing date or time".to_string()),
+        };
+        Row { line, record }
+    }).collect())
+}
+
+/// Split CSV text into rows of fields with the line each row starts on.
+/// Fields may be quoted with `"` (doubled inside) and then hold commas and
+/// line breaks.
+fn csv_rows(text: &str) -> Vec<(usize, Vec<String>)> {
+    let mut rows = Vec::new();
+    let (mut fields, mut field) = (Vec::new(), String::new());
+    let (mut line, mut start, mut quoted) = (1, 1, false);
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                fields.push(std::mem::take(&mut field));
+                if fields.iter().any(|f| !f.trim().is_empty()) {
+                    rows.push((start, std::mem::take(&mut fields)));
+                }
+                fields.clear();
+                line += 1;
+                start = line;
+            }
+            '\n' => {
+                field.push(c);
+                line += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    if fields.iter().any(|f| !f.trim().is_empty()) {
+        rows.push((start, fields));
+    }
+    rows
+}
+
+/// Chart every row in parallel, keeping the input order
+pub fn compute(rows: &[Row], gazetteer: &Gazetteer, dst: DstPolicy, options: &ChartOptions) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let workers = std::thread::available_parallelism().map_or(1, |n| n.get()).clamp(1, rows.len().max(1));
+    let mut done: Vec<(usize, Outcome)> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers).map(|_| scope.spawn(|| {
+            let mut out = Vec::new();
+            loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(row) = rows.get(i) else { break };
+                out.push((i, chart_row(row, gazetteer, dst, options)));
+            }
+            out
+        })).collect();
+        handles.into_iter()
+            .flat_map(|h| h.join().expect("batch worker panicked"))
+            .collect()
+    });
+    done.sort_by_key(|(i, _)| *i);
+    done.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+fn chart_row(row: &Row, gazetteer: &Gazetteer, dst: DstPolicy, options: &ChartOptions) -> Outcome {
+    let record = match &row.record {
+        Ok(record) => record,
+        Err(e) => return Outcome { line: row.line, name: String::new(), chart: Err(e.clone()) },
+    };
+    // Without a zone or location the default location is used, as for a
+    // single chart
+    let location = match (&record.tz, &record.location) {
+        (None, None) => Some(DEFAULT_LOCATION),
+        (_, location) => location.as_deref(),
+    };
+    let chart = Birth::resolve(&record.date, &record.time, record.tz.as_deref(), location, gazetteer, dst, options)
+        .and_then(|birth| {
+            let analysis = types::analyze(&birth.chart)?;
+            Ok((birth, analysis))
+        })
+        .map_err(|e| e.to_string());
+    Outcome { line: row.line, name: record.name.clone(), chart }
+}
+
+/// One output entry: the chart document of a row, or its error
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry<'a> {
+    pub line: usize,
+    pub name: &'a str,
+    #[serde(flatten)]
+    pub document: Option<ChartDocument<'a>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<&'a str>,
+    /// Location that was not geocoded because the record gave a zone
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+}
+
+impl<'a> Entry<'a> {
+    pub fn new(outcome: &'a Outcome) -> Self {
+        let (document, error, warning) = match &outcome.chart {
+            Ok((birth, analysis)) => (
+                Some(ChartDocument::new(birth.info(), &birth.chart, analysis)),
+                None,
+                birth.warning.as_ref().map(|w| w.to_string()),
+            ),
+            Err(e) => (None, Some(e.as_str()), None),
+        };
+        Entry { line: outcome.line, name: &outcome.name, document, error, warning }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_may_be_quoted() {
+        let text = "name,date\r\n\"Doe, Jane\",\"say \"\"hi\"\"\"\n\n\"two\nlines\",x\nlast,y";
+        let rows = csv_rows(text);
+        assert_eq!(rows, [
+            (1, vec!["name".to_string(), "date".to_string()]),
+            (2, vec!["Doe, Jane".to_string(), "say \"hi\"".to_string()]),
+            (4, vec!["two\nlines".to_string(), "x".to_string()]),
+            (6, vec!["last".to_string(), "y".to_string()]),
+        ]);
+    }
+
+    #[test]
+    fn csv_columns_are_found_by_header_name() {
+        let text = "\u{feff}Time,Name,Date,Timezone,Place\n14:30,Ann,1985-06-15,+3,\n09:00,Bob,,,Oslo\n";
+        let rows = read_records(text, false).unwrap();
+        assert_eq!(rows.len(), 2);
+        let ann = rows[0].record.as_ref().unwrap();
+        assert_eq!((rows[0].line, ann.name.as_str(), ann.date.as_str(), ann.time.as_str()), (2, "Ann", "1985-06-15", "14:30"));
+        assert_eq!((ann.tz.as_deref(), ann.location.as_deref()), (Some("+3"), None));
+        assert_eq!((rows[1].line, rows[1].record.as_ref().err().map(String::as_str)), (3, Some("missing date or time")));
+
+        assert!(read_records("name,date\nAnn,1985-06-15\n", false).is_err());
+        assert!(read_records("", false).unwrap().is_empty());
+    }
+
+    #[test]
+    fn jsonl_lines_are_read_one_by_one() {
+        let text = "{\"date\": \"1985-06-15\", \"time\": \"14:30\", \"timezone\": \"+3\"}\n\nnot json\n";
+        let rows = read_records(text, true).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].record.as_ref().unwrap().tz.as_deref(), Some("+3"));
+        assert_eq!(rows[1].line, 3);
+        assert!(rows[1].record.is_err());
+    }
+
+    #[test]
+    fn outcomes_keep_the_input_order() {
+        let mut text = "name,date,time,tz\n".to_string();
+        for day in 1..=28 {
+            text += &format!("p{day},1985-02-{day:02},12:00,+2\n");
+        }
+        let rows = read_records(&text, false).unwrap();
+        let outcomes = compute(&rows, &Gazetteer::bundled(), DstPolicy::Reject, &ChartOptions::default());
+        assert_eq!(outcomes.len(), 28);
+        for (i, o) in outcomes.iter().enumerate() {
+            assert_eq!((o.line, o.name.clone()), (i + 2, format!("p{}", i + 1)));
+            let (birth, _) = o.chart.as_ref().unwrap();
+            assert_eq!(birth.date, format!("1985-02-{:02}", i + 1));
+        }
+    }
+
+    #[test]
+    fn failing_rows_become_error_entries() {
+        let text = "name,date,time,tz,location\nAnn,1985-06-15,14:30,+3,\nBob,1985-13-01,14:30,+3,\nCid,,14:30,+3,\nDee,1985-06-15,14:30,+3,Atlantis\n";
+        let rows = read_records(text, false).unwrap();
+        let outcomes = compute(&rows, &Gazetteer::bundled(), DstPolicy::Reject, &ChartOptions::default());
+        let entries: Vec<serde_json::Value> = outcomes.iter()
+            .map(|o| serde_json::to_value(Entry::new(o)).unwrap())
+            .collect();
+
+        assert_eq!(entries.iter().map(|e| e["line"].as_u64().unwrap()).collect::<Vec<_>>(), [2, 3, 4, 5]);
+        assert_eq!(entries[0]["name"], "Ann");
+        assert!(entries[0].get("chart").is_some() && entries[0].get("error").is_none());
+        assert_eq!(entries[1]["name"], "Bob");
+        assert!(entries[1].get("chart").is_none());
+        assert!(entries[1]["error"].as_str().unwrap().contains("1985-13-01"), "{}", entries[1]);
+        assert_eq!(entries[2]["error"], "missing date or time");
+        // The zone was used; the unknown location stays with its record
+        assert!(entries[3].get("chart").is_some());
+        assert_eq!(entries[3]["warning"], "unknown location 'Atlantis'");
+        assert!(entries[0].get("warning").is_none());
+    }
+}
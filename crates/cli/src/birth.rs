@@ -0,0 +1,119 @@
+//! Resolving birth data given on the command line to a chart.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use hd_core::{chart, parse, BirthInfo, Chart, ChartOptions, DstPolicy, Gazetteer, HdError, Place, Zone};
+
+/// Location used when none is given
+pub const DEFAULT_LOCATION: &str = "Joensuu, Finland";
+
+/// Birth data resolved to a UTC offset, with the chart calculated
+pub struct Birth {
+    pub date: String,
+    pub time: String,
+    pub tz_name: String,
+    pub utc_offset: f64,
+    /// Birth moment in UTC
+    pub instant: DateTime<Utc>,
+    /// Location as given, or empty when only a zone was given
+    pub location: String,
+    pub place: Option<Place>,
+    pub chart: Chart,
+    /// Why the location could not be geocoded when an explicit zone was
+    /// used instead; the caller decides whether to show it
+    pub warning: Option<HdError>,
+}
+
+impl Birth {
+    /// Resolve a birth time at a location. An explicit `tz` takes precedence
+    /// over the location's zone, and a location that cannot be geocoded is
+    /// then only kept as a warning. Without a location only `tz` is used.
+    pub fn resolve(
+        date: &str,
+        time: &str,
+        tz: Option<&str>,
+        location: Option<&str>,
+        gazetteer: &Gazetteer,
+        dst: DstPolicy,
+        options: &ChartOptions,
+    ) -> Result<Birth, HdError> {
+        let (year, month, day) = parse::parse_date(date)?;
+        let hour = parse::parse_time(time)?;
+
+        let (place, warning) = match location.map(|l| gazetteer.resolve(l)) {
+            Some(Ok(place)) => (Some(place), None),
+            Some(Err(e)) if tz.is_some() => (None, Some(e)),
+            Some(Err(e)) => return Err(e),
+            None => (None, None),
+        };
+        let tz_name = match (tz, &place) {
+            (Some(tz), _) => tz.to_string(),
+            (None, Some(place)) => place.timezone.clone(),
+            (None, None) => return Err(HdError::UnknownLocation(location.unwrap_or_default().to_string())),
+        };
+        let zone = Zone::parse(&tz_name)?;
+        let resolved = zone.resolve(year, month, day, hour, dst)?;
+        let chart = chart::calculate_chart_with(year, month, day, hour, resolved.utc_offset, options)?;
+        let instant = NaiveDate::from_ymd_opt(year, month, day)
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+            .map(|midnight| midnight.and_utc() + Duration::seconds(((hour - resolved.utc_offset) * 3600.0).round() as i64))
+            .ok_or_else(|| HdError::InvalidDate(date.to_string()))?;
+
+        Ok(Birth {
+            date: date.to_string(),
+            time: time.to_string(),
+            tz_name,
+            utc_offset: resolved.utc_offset,
+            instant,
+            location: location.unwrap_or_default().to_string(),
+            place,
+            chart,
+            warning,
+        })
+    }
+
+    /// Whether `spec` starts like a birth spec, with a date such as
+    /// `1985-06-15`. Person ids may start with a digit but never hold `-`.
+    pub fn is_spec(spec: &str) -> bool {
+        let date = spec.split_whitespace().next().unwrap_or_default();
+        date.contains('-') && date.chars().all(|c| c.is_ascii_digit() || c == '-')
+    }
+
+    /// Resolve a compact birth spec: `DATE TIME [ZONE | LOCATION]`, e.g.
+    /// `"1985-06-15 14:30 Helsinki"` or `"1985-06-15 14:30 +2"`.
+    /// Without a zone or location the default location is used.
+    pub fn from_spec(spec: &str, gazetteer: &Gazetteer, dst: DstPolicy, options: &ChartOptions) -> Result<Birth, HdError> {
+        let mut parts = spec.trim().splitn(3, char::is_whitespace);
+        let date = parts.next().unwrap_or_default();
+        let time = parts.next().ok_or_else(|| HdError::InvalidTime(spec.to_string()))?;
+        let rest = parts.next().map(str::trim).unwrap_or_default();
+
+        if rest.is_empty() {
+            Birth::resolve(date, time, None, Some(DEFAULT_LOCATION), gazetteer, dst, options)
+        } else if Zone::parse(rest).is_ok() {
+            Birth::resolve(date, time, Some(rest), None, gazetteer, dst, options)
+        } else {
+            Birth::resolve(date, time, None, Some(rest), gazetteer, dst, options)
+        }
+    }
+
+    /// Label for display: the resolved place, else the location as given,
+    /// else the zone
+    pub fn location_label(&self) -> String {
+        match &self.place {
+            Some(place) => place.label(),
+            None if self.location.is_empty() => self.tz_name.clone(),
+            None => self.location.clone(),
+        }
+    }
+
+    pub fn info(&self) -> BirthInfo {
+        BirthInfo {
+            date: self.date.clone(),
+            time: self.time.clone(),
+            tz: self.tz_name.clone(),
+            utc_offset: self.utc_offset,
+            location: self.location.clone(),
+            place: self.place.clone(),
+        }
+    }
+}
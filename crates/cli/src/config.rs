@@ -0,0 +1,74 @@
+//! Defaults for the global options, read from a JSON config file.
+//!
+//! The file lives at `$XDG_CONFIG_HOME/hd/config.json` (or
+//! `~/.config/hd/config.json`) unless `--config` names another. Options given
+//! on the command line always win over the file.
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+/// Config file contents; every key is optional
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Config {
+    /// File the settings were read from
+    #[serde(skip)]
+    pub path: PathBuf,
+    /// text, json or jsonl
+    pub format: Option<String>,
+    /// auto, always or never
+    pub color: Option<String>,
+    /// true or mean
+    pub node: Option<String>,
+    /// reject, earlier or later
+    pub dst: Option<String>,
+    /// Extra bodies, e.g. ["chiron", "lilith"]
+    pub bodies: Option<Vec<String>>,
+    pub gazetteer: Option<String>,
+    pub ephe_path: Option<String>,
+    pub people_db: Option<String>,
+}
+
+/// Config file that cannot be read or has unknown or invalid settings
+#[derive(Debug)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid config '{}': {}", self.path.display(), self.message)
+    }
+}
+
+impl Config {
+    fn default_path() -> Option<PathBuf> {
+        std::env::var_os("XDG_CONFIG_HOME").filter(|d| !d.is_empty()).map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .map(|dir| dir.join("hd").join("config.json"))
+    }
+
+    /// Load the given file, or the default one if it exists
+    pub fn load(path: Option<&str>) -> Result<Config, ConfigError> {
+        let (path, required) = match path {
+            Some(path) => (PathBuf::from(path), true),
+            None => match Config::default_path() {
+                Some(path) => (path, false),
+                None => return Ok(Config::default()),
+            },
+        };
+        let text = match std::fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if !required && e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(ConfigError { path, message: e.to_string() }),
+        };
+        let config: Config = serde_json::from_str(&text).map_err(|e| ConfigError { path: path.clone(), message: e.to_string() })?;
+        Ok(Config { path, ..config })
+    }
+
+    /// Error for a setting with a value that is not accepted
+    pub fn invalid(&self, key: &str, value: &str) -> ConfigError {
+        ConfigError { path: self.path.clone(), message: format!("invalid {} '{}'", key, value) }
+    }
+}
@@ -1,9 +1,21 @@
+use crate::batch::Outcome;
+use crate::birth::Birth;
+use crate::people::Person;
+use chrono::{DateTime, Duration, Utc};
 use colored::Colorize;
-use crate::chart::Chart;
-use crate::types::ChartAnalysis;
-use crate::transit::TransitOverlay;
+use hd_core::chart::{Activation, Chart, NodeModel};
+use hd_core::composite::{Composite, ConnectionKind};
+use hd_core::penta::Penta;
+use hd_core::rectify::Rectification;
+use hd_core::search::{Query, SearchMatch};
+use hd_core::returns::{Cycle, PlanetReturn};
+use hd_core::geo::Place;
+use hd_core::ingress::Ingress;
+use hd_core::types::ChartAnalysis;
+use hd_core::variables::{Arrow, Variable};
+use hd_core::transit::{TransitOverlay, TransitWindow};
 
-pub fn print_header(date: &str, time: &str, location: &str) {
+pub fn print_header(date: &str, time: &str, tz: &str, utc_offset: f64, location: &str, place: Option<&Place>, node: NodeModel) {
     println!();
     println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
     println!("{}", "              HUMAN DESIGN CHART".bright_cyan().bold());
@@ -11,9 +23,31 @@ pub fn print_header(date: &str, time: &str, location: &str) {
     println!("  {} {} {} {} {}",
         "Birth:".dimmed(), date.white(), time.white(),
         "at".dimmed(), location.white());
+    if let Some(place) = place {
+        let lat = if place.latitude >= 0.0 { 'N' } else { 'S' };
+        let lon = if place.longitude >= 0.0 { 'E' } else { 'W' };
+        println!("  {} {}",
+            "Coords:".dimmed(),
+            format!("{:.4}°{} {:.4}°{}", place.latitude.abs(), lat, place.longitude.abs(), lon).white());
+    }
+    println!("  {} {} {}",
+        "Zone:".dimmed(), tz.white(), format!("(UTC{})", format_offset(utc_offset)).dimmed());
+    print_node_model(node);
     println!();
 }
 
+/// Node model line shared by the report headers
+fn print_node_model(node: NodeModel) {
+    println!("  {} {}", "Nodes:".dimmed(), node.name().white());
+}
+
+/// Format an hour offset as `+03:00`
+fn format_offset(hours: f64) -> String {
+    let minutes = (hours * 60.0).round() as i32;
+    let sign = if minutes < 0 { '-' } else { '+' };
+    format!("{}{:02}:{:02}", sign, minutes.abs() / 60, minutes.abs() % 60)
+}
+
 pub fn print_summary(analysis: &ChartAnalysis) {
     println!("{}", "───── Chart Summary ─────".bright_yellow().bold());
     println!("  {}          {}", "Type:".bright_white(), analysis.hd_type.name().bright_green().bold());
@@ -22,6 +56,7 @@ pub fn print_summary(analysis: &ChartAnalysis) {
     println!("  {}       {} ({})", "Profile:".bright_white(),
         format!("{}/{}", analysis.profile.0, analysis.profile.1).bright_green().bold(),
         analysis.profile_name.dimmed());
+    println!("  {}    {}", "Definition:".bright_white(), analysis.definition.name().green());
     println!("  {}     {}", "Signature:".bright_white(), analysis.hd_type.signature().green());
     println!("  {}      {}", "Not-Self:".bright_white(), analysis.hd_type.not_self().red());
     println!("  {} {}/{} | {}/{}",
@@ -30,53 +65,144 @@ pub fn print_summary(analysis: &ChartAnalysis) {
         analysis.incarnation_cross.1.to_string().bright_cyan(),
         analysis.incarnation_cross.2.to_string().red(),
         analysis.incarnation_cross.3.to_string().red());
+    if let Some(cross) = &analysis.cross {
+        println!("               {}", cross.name.green());
+        println!("  {}       {} {}", "Quarter:".bright_white(),
+            cross.quarter.name().green(), format!("({})", cross.quarter.theme()).dimmed());
+    }
     println!();
 }
 
 pub fn print_activations(chart: &Chart) {
     println!("{}", "───── Personality (Conscious ●) ─────".bright_white().bold());
     for a in &chart.personality {
-        println!("  {:12}  Gate {:>2}.{}", a.planet, a.gate.to_string().bright_cyan(), a.line);
+        println!("  {:12}  Gate {:>2}.{}  {}", a.planet, a.gate.to_string().bright_cyan(), a.line, sub_line(a).dimmed());
+    }
+    for a in &chart.personality_extras {
+        println!("  {:12}  Gate {:>2}.{}  {} {}", a.planet, a.gate.to_string().cyan(), a.line, sub_line(a).dimmed(), "(extra)".dimmed());
     }
 
     println!();
     println!("{}", "───── Design (Unconscious ●) ─────".red().bold());
+    println!("  {} {}", "At:".dimmed(), chart.design_at.format("%Y-%m-%d %H:%M:%S UTC").to_string().white());
     for a in &chart.design {
-        println!("  {:12}  Gate {:>2}.{}", a.planet, a.gate.to_string().red(), a.line);
+        println!("  {:12}  Gate {:>2}.{}  {}", a.planet, a.gate.to_string().red(), a.line, sub_line(a).dimmed());
+    }
+    for a in &chart.design_extras {
+        println!("  {:12}  Gate {:>2}.{}  {} {}", a.planet, a.gate.to_string().red(), a.line, sub_line(a).dimmed(), "(extra)".dimmed());
     }
     println!();
 }
 
+/// Color, tone and base of an activation, e.g. "C3 T4 B2"
+fn sub_line(a: &Activation) -> String {
+    format!("C{} T{} B{}", a.color, a.tone, a.base)
+}
+
+pub fn print_variables(analysis: &ChartAnalysis) {
+    let v = &analysis.variables;
+    println!("{} {}", "───── Variables ─────".bright_white().bold(), v.notation().dimmed());
+    for (label, var) in [
+        ("Determination", &v.determination),
+        ("Environment", &v.environment),
+        ("Motivation", &v.motivation),
+        ("Perspective", &v.perspective),
+    ] {
+        print_variable(label, var);
+    }
+    println!();
+}
+
+fn print_variable(label: &str, v: &Variable) {
+    let arrow = match v.arrow {
+        Arrow::Left => "◀ Left ",
+        Arrow::Right => "▶ Right",
+    };
+    let name = match v.refinement {
+        Some(r) => format!("{} ({})", v.name, r),
+        None => v.name.to_string(),
+    };
+    println!("  {:14} {}  {} · {}  {}",
+        label,
+        arrow.bright_yellow(),
+        name.white().bold(),
+        v.orientation.green(),
+        format!("[{} C{} T{}]", v.source, v.color, v.tone).dimmed());
+}
+
 pub fn print_channels(analysis: &ChartAnalysis) {
     println!("{}", "───── Defined Channels ─────".bright_magenta().bold());
     if analysis.defined_channels.is_empty() {
         println!("  {}", "None (Reflector)".dimmed());
     }
     for ch in &analysis.defined_channels {
-        println!("  {}-{}: {} ({} ↔ {})",
+        let circuit = match ch.sub_circuit {
+            Some(sc) => format!("{}/{}", ch.circuit.name(), sc.name()),
+            None => ch.circuit.name().to_string(),
+        };
+        println!("  {}-{}: {} ({} ↔ {}) {}",
             ch.gate1.to_string().bright_cyan(),
             ch.gate2.to_string().bright_cyan(),
             ch.name.white().bold(),
             ch.center1.name().dimmed(),
-            ch.center2.name().dimmed());
+            ch.center2.name().dimmed(),
+            circuit.dimmed());
+    }
+    println!();
+}
+
+pub fn print_definition(analysis: &ChartAnalysis) {
+    if analysis.definition_groups.len() < 2 {
+        return;
+    }
+    println!("{}", "───── Definition Areas ─────".bright_blue().bold());
+    for (i, group) in analysis.definition_groups.iter().enumerate() {
+        let names: Vec<&str> = group.iter().map(|c| c.name()).collect();
+        println!("  {} {}", format!("{}.", i + 1).dimmed(), names.join(", ").white());
+    }
+    if !analysis.bridging_gates.is_empty() {
+        println!("  {}", "Bridging gates:".bright_white());
+        for b in &analysis.bridging_gates {
+            println!("    {} {} ({}-{} {}, {} ↔ {})",
+                "Gate".dimmed(),
+                b.gate.to_string().bright_green(),
+                b.hanging_gate, b.gate,
+                b.channel_name.white(),
+                b.joins.0.name().dimmed(),
+                b.joins.1.name().dimmed());
+        }
+    }
+    println!();
+}
+
+pub fn print_circuitry(analysis: &ChartAnalysis) {
+    let balance = &analysis.circuitry;
+    if balance.dominant.is_empty() {
+        return;
+    }
+    println!("{}", "───── Circuitry ─────".bright_blue().bold());
+    for (circuit, count) in &balance.circuits {
+        let mut subs: Vec<String> = balance.sub_circuits.iter()
+            .filter(|(sc, n)| sc.circuit() == *circuit && **n > 0)
+            .map(|(sc, n)| format!("{} {}", sc.name(), n))
+            .collect();
+        if let Some(&n) = balance.hanging_gates.get(circuit).filter(|&&n| n > 0) {
+            subs.push(format!("{} hanging", n));
+        }
+        let line = format!("  {:<12} {}", circuit.name(), count);
+        let line = if balance.dominant.contains(circuit) { line.bright_white() } else { line.dimmed() };
+        if subs.is_empty() {
+            println!("{}", line);
+        } else {
+            println!("{} {}", line, format!("({})", subs.join(", ")).dimmed());
+        }
     }
     println!();
 }
 
 pub fn print_centers(analysis: &ChartAnalysis) {
     println!("{}", "───── Centers ─────".bright_blue().bold());
-    let all = [
-        crate::gates::Center::Head,
-        crate::gates::Center::Ajna,
-        crate::gates::Center::Throat,
-        crate::gates::Center::G,
-        crate::gates::Center::HeartEgo,
-        crate::gates::Center::SolarPlexus,
-        crate::gates::Center::Sacral,
-        crate::gates::Center::Spleen,
-        crate::gates::Center::Root,
-    ];
-    for c in &all {
+    for c in &hd_core::gates::Center::ALL {
         if analysis.defined_centers.contains(c) {
             println!("  {} {}", "■".bright_yellow(), c.name().bright_white());
         } else {
@@ -88,13 +214,14 @@ pub fn print_centers(analysis: &ChartAnalysis) {
 
 pub fn print_transits(overlay: &TransitOverlay) {
     println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
-    println!("{}", "              CURRENT TRANSITS".bright_cyan().bold());
+    println!("{}", "              TRANSITS".bright_cyan().bold());
     println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {}", "At:".dimmed(), overlay.at.format("%Y-%m-%d %H:%M UTC").to_string().white());
     println!();
 
     println!("{}", "───── Transit Positions ─────".bright_yellow().bold());
     for a in &overlay.transit_positions {
-        println!("  {:12}  Gate {:>2}.{}", a.planet, a.gate.to_string().yellow(), a.line);
+        println!("  {:12}  Gate {:>2}.{}  {}", a.planet, a.gate.to_string().yellow(), a.line, sub_line(a).dimmed());
     }
     println!();
 
@@ -121,7 +248,377 @@ pub fn print_transits(overlay: &TransitOverlay) {
             }
         }
     } else {
-        println!("  {}", "No additional channels activated by current transits.".dimmed());
+        println!("  {}", "No additional channels activated by these transits.".dimmed());
+    }
+    println!();
+}
+
+pub fn print_composite(a: &Birth, b: &Birth, composite: &Composite) {
+    let combined = &composite.combined;
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              COMPOSITE CHART".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    for (label, person) in [("A:", a), ("B:", b)] {
+        println!("  {} {} {} {} {}",
+            label.dimmed(), person.date.white(), person.time.white(),
+            "at".dimmed(), person.location_label().white());
+    }
+    print_node_model(a.chart.node_model);
+    println!();
+
+    println!("{}", "───── Combined ─────".bright_yellow().bold());
+    println!("  {}          {}", "Type:".bright_white(), combined.hd_type.name().bright_green().bold());
+    println!("  {}     {}", "Authority:".bright_white(), combined.authority.green());
+    println!("  {}    {}", "Definition:".bright_white(), combined.definition.name().green());
+    let kinds: Vec<String> = ConnectionKind::ALL.iter()
+        .map(|&k| format!("{} {}", k.name(), composite.count(k)))
+        .collect();
+    println!("  {}   {}", "Connections:".bright_white(), kinds.join(", ").dimmed());
+    println!();
+
+    println!("{}", "───── Channels ─────".bright_magenta().bold());
+    if composite.channels.is_empty() {
+        println!("  {}", "None".dimmed());
+    }
+    for c in &composite.channels {
+        let kind = match c.holder {
+            Some(p) => format!("{} ({})", c.kind.name(), p.name()),
+            None => c.kind.name().to_string(),
+        };
+        println!("  {}-{}: {} {}",
+            c.channel.gate1.to_string().bright_cyan(),
+            c.channel.gate2.to_string().bright_cyan(),
+            c.channel.name.white().bold(),
+            kind.dimmed());
+    }
+    println!();
+
+    println!("{}", "───── Centers ─────".bright_blue().bold());
+    for c in &hd_core::gates::Center::ALL {
+        if combined.defined_centers.contains(c) {
+            println!("  {} {}", "■".bright_yellow(), c.name().bright_white());
+        } else {
+            println!("  {} {}", "□".dimmed(), c.name().dimmed());
+        }
+    }
+    println!();
+}
+
+pub fn print_penta(members: &[Birth], penta: &Penta) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              PENTA".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    for (i, m) in members.iter().enumerate() {
+        println!("  {} {} {} {} {}",
+            format!("{}.", i + 1).dimmed(), m.date.white(), m.time.white(),
+            "at".dimmed(), m.location_label().white());
+    }
+    if let Some(first) = members.first() {
+        print_node_model(first.chart.node_model);
+    }
+    println!();
+
+    let numbers = |idx: &[usize]| idx.iter().map(|i| (i + 1).to_string()).collect::<Vec<_>>().join(", ");
+
+    println!("{}", "───── Penta Gates ─────".bright_yellow().bold());
+    for g in &penta.gates {
+        let line = format!("{:>2} {:<26} {:<8}", g.gate, g.keynote, g.center.name());
+        if g.is_filled() {
+            println!("  {} {} {}", "■".bright_yellow(), line.bright_white(), numbers(&g.members).green());
+        } else {
+            println!("  {} {} {}", "□".dimmed(), line.dimmed(), "missing".red());
+        }
+    }
+    println!("  {} {} of 12", "Filled:".bright_white(), penta.filled.len().to_string().bright_green());
+    println!();
+
+    println!("{}", "───── Roles ─────".bright_magenta().bold());
+    for r in &penta.roles {
+        let status = if r.covered { "covered".green() } else { "open".red() };
+        let held = if r.held_by.is_empty() {
+            String::new()
+        } else {
+            format!("(held by {})", numbers(&r.held_by))
+        };
+        println!("  {:<20} {}-{} {} {}",
+            r.role.white().bold(),
+            r.gate1.to_string().bright_cyan(),
+            r.gate2.to_string().bright_cyan(),
+            status,
+            held.dimmed());
+    }
+    println!();
+}
+
+pub fn print_ingresses(from: DateTime<Utc>, to: DateTime<Utc>, node: NodeModel, ingresses: &[Ingress]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              TRANSIT INGRESSES".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {} {} {}",
+        "From:".dimmed(), from.format("%Y-%m-%d %H:%M UTC").to_string().white(),
+        "to".dimmed(), to.format("%Y-%m-%d %H:%M UTC").to_string().white());
+    print_node_model(node);
+    println!();
+
+    if ingresses.is_empty() {
+        println!("  {}", "No ingresses in this range.".dimmed());
+    }
+    for i in ingresses {
+        let entered = format!("{:>2}.{}", i.gate, i.line);
+        let entered = if i.is_gate_change() { entered.bright_yellow().bold() } else { entered.yellow() };
+        println!("  {}  {:12} {} {} {}{}",
+            i.at.format("%Y-%m-%d %H:%M:%S").to_string().dimmed(),
+            i.planet,
+            entered,
+            "from".dimmed(),
+            format!("{}.{}", i.from_gate, i.from_line).dimmed(),
+            if i.retrograde { " ℞".red().to_string() } else { String::new() });
+    }
+    println!();
+}
+
+pub fn print_forecast(birth: &Birth, from: DateTime<Utc>, to: DateTime<Utc>, windows: &[TransitWindow]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              TRANSIT FORECAST".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {} {} {} {}",
+        "Birth:".dimmed(), birth.date.white(), birth.time.white(),
+        "at".dimmed(), birth.location_label().white());
+    println!("  {} {} {} {}",
+        "From:".dimmed(), from.format("%Y-%m-%d %H:%M UTC").to_string().white(),
+        "to".dimmed(), to.format("%Y-%m-%d %H:%M UTC").to_string().white());
+    print_node_model(birth.chart.node_model);
+    println!();
+
+    if windows.is_empty() {
+        println!("  {}", "No natal channels completed by transits in this range.".dimmed());
+    }
+    for w in windows {
+        let opened = if w.opened_centers.is_empty() {
+            String::new()
+        } else {
+            let names: Vec<&str> = w.opened_centers.iter().map(|c| c.name()).collect();
+            format!(" defines {}", names.join(", "))
+        };
+        println!("  {} → {}  {:12} {}-{}: {} (natal {} + transit {}){}",
+            w.start.format("%Y-%m-%d %H:%M").to_string().dimmed(),
+            w.end.format("%Y-%m-%d %H:%M").to_string().dimmed(),
+            w.planet,
+            w.channel.gate1.to_string().bright_green(),
+            w.channel.gate2.to_string().bright_green(),
+            w.channel.name.white().bold(),
+            w.channel.natal_gate.to_string().bright_cyan(),
+            w.channel.transit_gate.to_string().yellow(),
+            opened.bright_yellow());
+    }
+    println!();
+}
+
+pub fn print_returns(birth: &Birth, cycle: Cycle, from: DateTime<Utc>, to: DateTime<Utc>, returns: &[PlanetReturn]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", format!("              {}", cycle.name().to_uppercase()).bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {} {} {} {}",
+        "Birth:".dimmed(), birth.date.white(), birth.time.white(),
+        "at".dimmed(), birth.location_label().white());
+    println!("  {} {} {} {}",
+        "From:".dimmed(), from.format("%Y-%m-%d %H:%M UTC").to_string().white(),
+        "to".dimmed(), to.format("%Y-%m-%d %H:%M UTC").to_string().white());
+    print_node_model(birth.chart.node_model);
+    println!();
+
+    if returns.is_empty() {
+        println!("  {}", format!("No {} in this range.", cycle.name()).dimmed());
+        println!();
+    }
+    for (i, r) in returns.iter().enumerate() {
+        let pass = if r.retrograde { " ℞" } else { "" };
+        println!("{}", format!("───── {} {} of {}: {}{} ─────",
+            cycle.name(), i + 1, returns.len(), r.at.format("%Y-%m-%d %H:%M:%S UTC"), pass).bright_yellow().bold());
+        let a = &r.analysis;
+        println!("  {:14} {}", "Type:".dimmed(), a.hd_type.name().bright_green().bold());
+        println!("  {:14} {}", "Authority:".dimmed(), a.authority.white());
+        println!("  {:14} {}", "Definition:".dimmed(), a.definition.name().white());
+        for ch in &a.defined_channels {
+            println!("  {}-{}: {} ({} ↔ {})",
+                ch.gate1.to_string().yellow(),
+                ch.gate2.to_string().yellow(),
+                ch.name.white(),
+                ch.center1.name().dimmed(),
+                ch.center2.name().dimmed());
+        }
+        if r.overlay.is_empty() {
+            println!("  {}", "Completes no channels in the natal chart.".dimmed());
+        } else {
+            println!("  {}", "Over the natal chart:".dimmed());
+            for ch in r.overlay.iter().filter(|ch| ch.natal_gate == 0) {
+                println!("    {}-{}: {} {}",
+                    ch.gate1.to_string().yellow(),
+                    ch.gate2.to_string().yellow(),
+                    ch.name.white().bold(),
+                    "[return only]".dimmed());
+            }
+            for ch in r.overlay.iter().filter(|ch| ch.natal_gate != 0) {
+                println!("    {}-{}: {} (natal {} + return {})",
+                    ch.gate1.to_string().bright_green(),
+                    ch.gate2.to_string().bright_green(),
+                    ch.name.white().bold(),
+                    ch.natal_gate.to_string().bright_cyan(),
+                    ch.transit_gate.to_string().yellow());
+            }
+        }
+        println!();
+    }
+}
+
+pub fn print_rectification(start: &Birth, end: &Birth, rectification: &Rectification) {
+    // Times are shown in the zone offset at the start of the window
+    let local = |at: DateTime<Utc>| (at + Duration::seconds((start.utc_offset * 3600.0).round() as i64))
+        .format("%H:%M:%S").to_string();
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              BIRTH TIME RECTIFICATION".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {} {} {}",
+        "Date:".dimmed(), start.date.white(), "at".dimmed(), start.location_label().white());
+    println!("  {} {} {} {} {}",
+        "Window:".dimmed(), start.time.white(), "to".dimmed(), end.time.white(),
+        format!("({}, UTC{})", start.tz_name, format_offset(start.utc_offset)).dimmed());
+    print_node_model(start.chart.node_model);
+    println!();
+
+    println!("{}", "───── Boundaries ─────".bright_yellow().bold());
+    if rectification.boundaries.is_empty() {
+        println!("  {}", "No activation changes line in this window.".dimmed());
+    }
+    for b in &rectification.boundaries {
+        let changes: Vec<String> = b.changes.iter()
+            .map(|c| {
+                let text = format!("{} {}.{} → {}.{}", c.planet, c.from_gate, c.from_line, c.gate, c.line);
+                if c.design { format!("{} {}", text.red(), "(design)".dimmed()) } else { text }
+            })
+            .collect();
+        println!("  {}  {}", local(b.at).dimmed(), changes.join(", "));
+    }
+    println!();
+
+    println!("{}", "───── Charts ─────".bright_yellow().bold());
+    for w in &rectification.windows {
+        let a = &w.analysis;
+        println!("  {} – {}  {} {} {}",
+            local(w.from).white(), local(w.to).white(),
+            a.hd_type.name().bright_green().bold(),
+            format!("{}/{}", a.profile.0, a.profile.1).bright_green(),
+            a.authority.green());
+        if let Some(cross) = &a.cross {
+            println!("    {}", cross.name.dimmed());
+        }
+        let channels: Vec<String> = a.defined_channels.iter()
+            .map(|ch| format!("{}-{}", ch.gate1, ch.gate2))
+            .collect();
+        println!("    {} {}", "Channels:".dimmed(),
+            if channels.is_empty() { "none".to_string() } else { channels.join(", ") });
+    }
+    println!();
+}
+
+pub fn print_search(query: &Query, from: DateTime<Utc>, to: DateTime<Utc>, node: NodeModel, matches: &[SearchMatch]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              CHART SEARCH".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("  {} {} {} {}",
+        "From:".dimmed(), from.format("%Y-%m-%d %H:%M UTC").to_string().white(),
+        "to".dimmed(), to.format("%Y-%m-%d %H:%M UTC").to_string().white());
+    print_node_model(node);
+
+    let mut constraints: Vec<String> = query.activations.iter()
+        .map(|c| match c.line {
+            Some(line) => format!("{} {} {}.{}", c.side.name(), c.planet, c.gate, line),
+            None => format!("{} {} {}", c.side.name(), c.planet, c.gate),
+        })
+        .collect();
+    constraints.extend(query.hd_type.map(|t| t.name().to_string()));
+    constraints.extend(query.authority.as_ref().map(|a| format!("{} authority", a)));
+    constraints.extend(query.profile.map(|(p, d)| format!("{}/{} profile", p, d)));
+    constraints.extend(query.channels.iter().map(|(a, b)| format!("channel {}-{}", a, b)));
+    if !constraints.is_empty() {
+        println!("  {} {}", "Where:".dimmed(), constraints.join(", ").white());
+    }
+    println!();
+
+    if matches.is_empty() {
+        println!("  {}", "No matching birth moments in this range.".dimmed());
+    }
+    for m in matches {
+        let minutes = (m.to - m.from).num_minutes();
+        println!("  {} → {}  {}",
+            m.from.format("%Y-%m-%d %H:%M:%S").to_string().white(),
+            m.to.format("%Y-%m-%d %H:%M:%S").to_string().white(),
+            format!("({}d {}h {}m)", minutes / 1440, minutes / 60 % 24, minutes % 60).dimmed());
+    }
+    println!();
+}
+
+pub fn print_batch(outcomes: &[Outcome]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              BATCH".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    let failed = outcomes.iter().filter(|o| o.chart.is_err()).count();
+    println!("  {} {} {}",
+        "Records:".dimmed(), outcomes.len().to_string().white(),
+        format!("({} failed)", failed).dimmed());
+    if let Some((birth, _)) = outcomes.iter().find_map(|o| o.chart.as_ref().ok()) {
+        print_node_model(birth.chart.node_model);
+    }
+    println!();
+
+    for o in outcomes {
+        let name = if o.name.is_empty() { format!("line {}", o.line) } else { o.name.clone() };
+        match &o.chart {
+            Ok((birth, analysis)) => println!("  {:<20} {} {}  {:<22} {:<4} {}",
+                name.bright_white(),
+                birth.date.white(), birth.time.white(),
+                analysis.hd_type.name().green(),
+                format!("{}/{}", analysis.profile.0, analysis.profile.1).bright_green(),
+                analysis.authority.green()),
+            Err(e) => println!("  {:<20} {}", name.bright_white(), e.red()),
+        }
+    }
+    println!();
+}
+
+pub fn print_people(people: &[&Person]) {
+    println!();
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    println!("{}", "              PEOPLE".bright_cyan().bold());
+    println!("{}", "═══════════════════════════════════════════════════════".bright_cyan());
+    if let Some(summary) = people.iter().find_map(|p| p.cached.as_ref()) {
+        print_node_model(summary.node_model);
+    }
+    println!();
+
+    if people.is_empty() {
+        println!("  {}", "No saved people.".dimmed());
+    }
+    for p in people {
+        println!("  {:<20} {} {} {}  {}",
+            p.data.name.bright_white(), p.data.id.dimmed(),
+            p.date().white(), p.time().white(),
+            p.location.as_deref().or(p.tz.as_deref()).unwrap_or_default().dimmed());
+        if let Some(summary) = &p.cached {
+            print!("  {:<20} {:<22} {:<4} {}", "", summary.hd_type.green(), summary.profile.bright_green(), summary.authority.green());
+        }
+        if !p.groups.is_empty() {
+            print!("  {}", format!("[{}]", p.groups.join(", ")).cyan());
+        }
+        println!();
     }
     println!();
 }
@@ -1,107 +1,1103 @@
-mod chart;
+mod batch;
+mod birth;
+mod config;
 mod display;
-mod gates;
-mod svg;
-mod transit;
-mod types;
+mod people;
 
-use clap::Parser;
+use birth::Birth;
+use config::{Config, ConfigError};
+use people::{People, PeopleError};
+use chrono::{DateTime, Utc};
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use hd_core::{
+    chart, composite, ical, ingress, parse, penta, rectify, returns, search, svg, transit, types, Chart,
+    ChartAnalysis, ChartDocument, ChartOptions, CompositeDocument, DstPolicy, ExtraBody, ForecastDocument,
+    Gazetteer, HdError, HdType, NodeModel, PentaDocument, RectifyDocument, ReturnDocument, SearchDocument,
+    TimelineDocument, TransitDocument,
+};
 use std::collections::HashSet;
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+/// Without a subcommand the chart options are taken as `hd chart` takes
+/// them, so `hd --date ... --time ...` keeps working
 #[derive(Parser)]
-#[command(name = "hd", about = "Human Design Chart Calculator")]
+#[command(
+    name = "hd",
+    about = "Human Design Chart Calculator",
+    subcommand_negates_reqs = true,
+    arg_required_else_help = true,
+)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    chart: ChartArgs,
+
+    /// Resolution of ambiguous or skipped local times around DST changes
+    #[arg(long, value_enum, default_value_t = Dst::Reject, global = true)]
+    dst: Dst,
+
+    /// GeoNames cities dump to geocode against instead of the bundled city list
+    #[arg(long, global = true)]
+    gazetteer: Option<String>,
+
+    /// Output SVG bodygraph to file
+    #[arg(long, global = true)]
+    svg: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text, global = true)]
+    format: Format,
+
+    /// When to color text output
+    #[arg(long, value_enum, default_value_t = Color::Auto, global = true)]
+    color: Color,
+
+    /// JSON config file with defaults for format, color, node, dst, bodies,
+    /// gazetteer, ephePath and peopleDb; defaults to
+    /// $XDG_CONFIG_HOME/hd/config.json when it exists
+    #[arg(long, global = true)]
+    config: Option<String>,
+
+    /// Extra bodies to list beside the 13 activations, comma-separated:
+    /// chiron, lilith. They are shown but never define channels or centers
+    #[arg(long, value_delimiter = ',', value_parser = parse_body, global = true)]
+    bodies: Vec<ExtraBody>,
+
+    /// Lunar node model for the North and South Node
+    #[arg(long, value_enum, default_value_t = Node::True, global = true)]
+    node: Node,
+
+    /// Directory with Swiss Ephemeris data files; needed for Chiron (seas_18.se1)
+    #[arg(long, global = true)]
+    ephe_path: Option<String>,
+
+    /// People database file; defaults to $XDG_DATA_HOME/hd/people.json
+    #[arg(long, global = true)]
+    people_db: Option<String>,
+}
+
+/// Birth data and overlay of a single chart
+#[derive(clap::Args)]
+struct ChartArgs {
     /// Birth date (YYYY-MM-DD)
-    #[arg(short, long)]
-    date: String,
+    #[arg(short, long, required_unless_present = "birth")]
+    date: Option<String>,
 
     /// Birth time (HH:MM)
-    #[arg(short, long)]
-    time: String,
+    #[arg(short, long, required_unless_present = "birth")]
+    time: Option<String>,
+
+    /// Saved person (name or id) or a person JSON file, instead of --date
+    /// and --time
+    #[arg(long, conflicts_with_all = ["date", "time", "tz"])]
+    birth: Option<String>,
 
-    /// Timezone offset from UTC (e.g., +2 for EET)
-    #[arg(long, default_value = "+2")]
-    tz: String,
+    /// Timezone: IANA name (e.g., Europe/Helsinki) or fixed UTC offset (e.g., +2).
+    /// Defaults to the zone of the resolved location
+    #[arg(long, allow_hyphen_values = true)]
+    tz: Option<String>,
 
-    /// Location name (for display only)
-    #[arg(short, long, default_value = "Joensuu, Finland")]
+    /// Birth place, optionally qualified by region or country (e.g., "Portland, OR")
+    #[arg(short, long, default_value = birth::DEFAULT_LOCATION)]
     location: String,
 
     /// Include current transit overlay
     #[arg(long)]
     transit: bool,
 
-    /// Output SVG bodygraph to file
-    #[arg(long)]
-    svg: Option<String>,
+    /// Include the transit overlay at an instant: 2026-12-21T10:00Z, now, or
+    /// relative to now like +7d, -12h
+    #[arg(long, value_name = "INSTANT", allow_hyphen_values = true)]
+    transit_at: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Chart of one birth: activations, type, authority, profile and channels
+    Chart(ChartArgs),
+    /// Transit positions at an instant, overlaid on a natal chart with --birth
+    Transit {
+        /// Instant: 2026-12-21T10:00Z, now, or relative to now like +7d, -12h
+        #[arg(long, default_value = "now", allow_hyphen_values = true)]
+        at: String,
+
+        /// Natal birth as "DATE TIME [ZONE | LOCATION]", a saved person or a person file
+        #[arg(long)]
+        birth: Option<String>,
+    },
+    /// Write the bodygraph of one birth as SVG, without the text report
+    Svg {
+        /// SVG file to write
+        output: String,
+
+        #[command(flatten)]
+        chart: ChartArgs,
+    },
+    /// Composite (connection) chart of two people
+    Composite {
+        /// Person A as "DATE TIME [ZONE | LOCATION]", e.g. "1985-06-15 14:30 Helsinki",
+        /// or a saved person or person file
+        #[arg(long)]
+        a: String,
+
+        /// Person B, in the same forms as --a
+        #[arg(long)]
+        b: String,
+    },
+    /// Timeline of transit ingresses into new gates and lines
+    Transits {
+        /// Start instant: 2026-11-01, 2026-11-01T06:00Z, now or +7d
+        #[arg(long, allow_hyphen_values = true)]
+        from: String,
+
+        /// End instant, in the same forms as --from
+        #[arg(long, allow_hyphen_values = true)]
+        to: String,
+
+        /// Only list gate changes, not line changes within a gate
+        #[arg(long)]
+        gates_only: bool,
+
+        /// Only list these bodies (e.g. --planet Sun --planet Moon)
+        #[arg(long = "planet")]
+        planets: Vec<String>,
+
+        /// Also write the ingresses as an iCalendar (.ics) file
+        #[arg(long)]
+        ics: Option<String>,
+    },
+    /// Forecast when transits complete channels in a natal chart
+    Forecast {
+        /// Natal birth as "DATE TIME [ZONE | LOCATION]", a saved person or a person file
+        #[arg(long)]
+        birth: String,
+
+        /// Start instant: 2026-11-01, 2026-11-01T06:00Z, now or +7d
+        #[arg(long, allow_hyphen_values = true)]
+        from: String,
+
+        /// End instant, in the same forms as --from
+        #[arg(long, allow_hyphen_values = true)]
+        to: String,
+
+        /// Only include these bodies (e.g. --planet Sun --planet Mars)
+        #[arg(long = "planet")]
+        planets: Vec<String>,
+
+        /// Also write the windows as an iCalendar (.ics) file
+        #[arg(long)]
+        ics: Option<String>,
+    },
+    /// Planetary return chart: Saturn return, Uranus opposition, Chiron return or solar return
+    Return {
+        /// Natal birth as "DATE TIME [ZONE | LOCATION]", a saved person or a person file
+        #[arg(long)]
+        birth: String,
+
+        /// Returning body: sun, saturn, uranus (opposition) or chiron
+        #[arg(long, value_parser = parse_cycle)]
+        planet: returns::Cycle,
+
+        /// Start of the search; defaults to shortly before the first expected
+        /// return (the next solar return from now)
+        #[arg(long, allow_hyphen_values = true)]
+        from: Option<String>,
+
+        /// End of the search, in the same forms as --from
+        #[arg(long, allow_hyphen_values = true)]
+        to: Option<String>,
+    },
+    /// Birth-time rectification: every activation change across a window of
+    /// possible birth times, and the distinct charts within it
+    Rectify {
+        /// Birth date (YYYY-MM-DD)
+        #[arg(short, long)]
+        date: String,
+
+        /// Earliest possible birth time (HH:MM)
+        #[arg(long)]
+        from: String,
+
+        /// Latest possible birth time (HH:MM)
+        #[arg(long)]
+        to: String,
+
+        /// Timezone: IANA name or fixed UTC offset; defaults to the location's zone
+        #[arg(long, allow_hyphen_values = true)]
+        tz: Option<String>,
+
+        /// Birth place
+        #[arg(short, long, default_value = birth::DEFAULT_LOCATION)]
+        location: String,
+    },
+    /// Find birth moments whose chart matches activations and properties
+    Search {
+        /// Start instant: 1990-01-01, 1990-01-01T06:00Z, now or -520w
+        #[arg(long, allow_hyphen_values = true)]
+        from: String,
+
+        /// End instant, in the same forms as --from
+        #[arg(long, allow_hyphen_values = true)]
+        to: String,
+
+        /// Activation as "[design] PLANET GATE[.LINE]", e.g. "Sun 13" or
+        /// "design Moon 41.3"; repeat to require several
+        #[arg(short, long = "activation")]
+        activations: Vec<String>,
+
+        /// Type, e.g. projector or manifesting-generator
+        #[arg(long = "type", value_parser = parse_type)]
+        hd_type: Option<HdType>,
+
+        /// Authority, matched by prefix: emotional, sacral, splenic, ego, self, mental, lunar
+        #[arg(long)]
+        authority: Option<String>,
+
+        /// Defined channel as GATE-GATE, e.g. 1-8; repeat to require several
+        #[arg(long = "channel", value_parser = search::parse_channel)]
+        channels: Vec<(u8, u8)>,
+
+        /// Profile as LINE/LINE, e.g. 1/3
+        #[arg(long, value_parser = search::parse_profile)]
+        profile: Option<(u8, u8)>,
+
+        /// Report at most this many matches
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+    /// Charts for many people at once from a CSV or JSONL file
+    Batch {
+        /// CSV with a header row (name, date, time, tz, location) or JSON
+        /// Lines with those fields; "-" reads standard input
+        file: String,
+
+        /// Read the input as JSON Lines; implied by a .jsonl or .ndjson file
+        #[arg(long)]
+        jsonl: bool,
+    },
+    /// Penta analysis of a group of 3 to 5 people
+    Penta {
+        /// Group member as "DATE TIME [ZONE | LOCATION]", a saved person or a
+        /// person file; repeat for 3 to 5 members in all
+        #[arg(short, long = "member", required_unless_present = "group")]
+        members: Vec<String>,
+
+        /// Add every saved person in this group as a member
+        #[arg(long)]
+        group: Option<String>,
+    },
+    /// Saved people: birth data stored once and used by name
+    People {
+        #[command(subcommand)]
+        command: PeopleCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum PeopleCommand {
+    /// Save a person
+    Add {
+        name: String,
+
+        /// Birth date (YYYY-MM-DD)
+        #[arg(short, long)]
+        date: String,
+
+        /// Birth time (HH:MM)
+        #[arg(short, long)]
+        time: String,
+
+        /// Timezone: IANA name or fixed UTC offset; defaults to the location's zone
+        #[arg(long, allow_hyphen_values = true)]
+        tz: Option<String>,
+
+        /// Birth place; defaults to the default location when no --tz is given
+        #[arg(short, long)]
+        location: Option<String>,
+
+        /// Group to tag the person with; repeat for several
+        #[arg(short, long = "group")]
+        groups: Vec<String>,
+    },
+    /// List saved people with their type, profile and authority
+    List {
+        /// Only people in this group
+        #[arg(short, long)]
+        group: Option<String>,
+    },
+    /// Chart of a saved person
+    Show {
+        /// Name or id
+        person: String,
+    },
+    /// Remove a saved person
+    Rm {
+        /// Name or id
+        person: String,
+    },
+    /// Change a saved person's name, birth data or groups
+    Edit {
+        /// Name or id
+        person: String,
+
+        #[arg(long)]
+        name: Option<String>,
+
+        #[arg(short, long)]
+        date: Option<String>,
+
+        #[arg(short, long)]
+        time: Option<String>,
+
+        #[arg(long, allow_hyphen_values = true)]
+        tz: Option<String>,
+
+        #[arg(short, long)]
+        location: Option<String>,
+
+        /// Tag with a group; repeat for several
+        #[arg(short, long = "group")]
+        groups: Vec<String>,
+
+        /// Remove a group tag; repeat for several
+        #[arg(long = "ungroup")]
+        ungroups: Vec<String>,
+    },
+    /// Import people from the web app's JSON (a person or a list of persons);
+    /// persons with a known id are updated
+    Import {
+        file: String,
+    },
+    /// Export people as the web app's JSON, to a file or standard output
+    Export {
+        file: Option<String>,
+
+        /// Only people in this group
+        #[arg(short, long)]
+        group: Option<String>,
+    },
 }
 
-fn main() {
-    let args = Args::parse();
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Dst {
+    /// Fail and report both possible offsets
+    Reject,
+    /// Use the earlier of the two possible instants
+    Earlier,
+    /// Use the later of the two possible instants
+    Later,
+}
 
-    // Parse date
-    let parts: Vec<&str> = args.date.split('-').collect();
-    let year: i32 = parts[0].parse().expect("Invalid year");
-    let month: u32 = parts[1].parse().expect("Invalid month");
-    let day: u32 = parts[2].parse().expect("Invalid day");
+impl From<Dst> for DstPolicy {
+    fn from(d: Dst) -> Self {
+        match d {
+            Dst::Reject => DstPolicy::Reject,
+            Dst::Earlier => DstPolicy::Earlier,
+            Dst::Later => DstPolicy::Later,
+        }
+    }
+}
 
-    // Parse time
-    let time_parts: Vec<&str> = args.time.split(':').collect();
-    let hour: f64 = time_parts[0].parse::<f64>().unwrap()
-        + time_parts[1].parse::<f64>().unwrap() / 60.0;
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Node {
+    /// True (osculating) node, as most HD software uses
+    True,
+    /// Mean node, smoothed of the Moon's short-term wobble
+    Mean,
+}
 
-    // Parse timezone
-    let tz: f64 = args.tz.replace("+", "").parse().expect("Invalid timezone offset");
+impl From<Node> for NodeModel {
+    fn from(n: Node) -> Self {
+        match n {
+            Node::True => NodeModel::True,
+            Node::Mean => NodeModel::Mean,
+        }
+    }
+}
 
-    // Calculate chart
-    let hd_chart = chart::calculate_chart(year, month, day, hour, tz);
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Color {
+    /// Color when writing to a terminal, unless NO_COLOR is set
+    Auto,
+    Always,
+    Never,
+}
 
-    // Analyze
-    let analysis = types::analyze(&hd_chart);
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// Colored human-readable text
+    Text,
+    /// Versioned JSON document
+    Json,
+    /// The JSON document on a single line; one line per record for batch
+    Jsonl,
+}
 
-    // Display
-    display::print_header(&args.date, &args.time, &args.location);
-    display::print_summary(&analysis);
-    display::print_activations(&hd_chart);
-    display::print_channels(&analysis);
-    display::print_centers(&analysis);
+/// CLI failure, mapped to a distinct process exit code
+#[derive(Debug)]
+enum CliError {
+    Hd(HdError),
+    Io(String, std::io::Error),
+    Read(String, std::io::Error),
+    Batch(String, String),
+    People(PeopleError),
+    Config(ConfigError),
+    Json(serde_json::Error),
+}
 
-    // Transit overlay
-    let mut transit_gate_set: Option<HashSet<u8>> = None;
-    if args.transit {
-        let transit_positions = chart::calculate_transit();
+impl CliError {
+    /// Exit codes: 2 bad input, 3 date out of range, 4 ephemeris failure,
+    /// 5 incomplete chart, 6 I/O error
+    fn exit_code(&self) -> u8 {
+        match self {
+            CliError::Hd(HdError::InvalidDate(_))
+            | CliError::Hd(HdError::InvalidInstant(_))
+            | CliError::Hd(HdError::InvalidRange(_))
+            | CliError::Hd(HdError::InvalidTime(_))
+            | CliError::Hd(HdError::InvalidTimezone(_))
+            | CliError::Hd(HdError::AmbiguousLocalTime(_))
+            | CliError::Hd(HdError::NonexistentLocalTime(_))
+            | CliError::Hd(HdError::UnknownLocation(_))
+            | CliError::Hd(HdError::AmbiguousLocation { .. })
+            | CliError::Hd(HdError::GroupSize(_))
+            | CliError::Hd(HdError::InvalidQuery(_))
+            | CliError::Batch(..)
+            | CliError::Config(_)
+            | CliError::People(PeopleError::Unknown(_) | PeopleError::Ambiguous(..) | PeopleError::Duplicate(_)) => 2,
+            CliError::Hd(HdError::DateOutOfRange(_)) => 3,
+            CliError::Hd(HdError::Ephemeris(_)) | CliError::Hd(HdError::DesignNotConverged(_)) => 4,
+            CliError::Hd(HdError::MissingActivation(_)) => 5,
+            CliError::Hd(HdError::Gazetteer(_)) | CliError::Io(..) | CliError::Read(..) | CliError::Json(_) => 6,
+            CliError::People(_) => 6,
+        }
+    }
+}
 
-        // Collect natal gates
-        let natal_gates: HashSet<u8> = hd_chart.personality.iter()
-            .chain(hd_chart.design.iter())
-            .map(|a| a.gate)
-            .collect();
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Hd(e) => write!(f, "{}", e),
+            CliError::Io(path, e) => write!(f, "cannot write '{}': {}", path, e),
+            CliError::Read(path, e) => write!(f, "cannot read '{}': {}", path, e),
+            CliError::Batch(path, e) => write!(f, "cannot read records from '{}': {}", path, e),
+            CliError::People(e) => write!(f, "{}", e),
+            CliError::Config(e) => write!(f, "{}", e),
+            CliError::Json(e) => write!(f, "cannot write JSON output: {}", e),
+        }
+    }
+}
+
+impl From<HdError> for CliError {
+    fn from(e: HdError) -> Self {
+        CliError::Hd(e)
+    }
+}
+
+impl From<ConfigError> for CliError {
+    fn from(e: ConfigError) -> Self {
+        CliError::Config(e)
+    }
+}
+
+impl From<PeopleError> for CliError {
+    fn from(e: PeopleError) -> Self {
+        CliError::People(e)
+    }
+}
 
-        let t_gates: HashSet<u8> = transit_positions.iter().map(|a| a.gate).collect();
-        transit_gate_set = Some(t_gates);
+impl Args {
+    fn chart_options(&self) -> ChartOptions {
+        ChartOptions { node_model: self.node.into(), bodies: self.bodies.clone() }
+    }
 
-        let completed = transit::overlay_transits(
-            &natal_gates,
-            &transit_positions,
-            &analysis.defined_channels,
-        );
+    fn people(&self) -> Result<People, CliError> {
+        let path = match &self.people_db {
+            Some(path) => PathBuf::from(path),
+            None => People::default_path()?,
+        };
+        Ok(People::open(path)?)
+    }
 
-        let overlay = transit::TransitOverlay {
-            transit_positions,
-            completed_channels: completed,
+    /// Birth given as "DATE TIME [ZONE | LOCATION]", a person JSON file, or
+    /// the name or id of a saved person
+    fn resolve_birth(&self, gazetteer: &Gazetteer, spec: &str) -> Result<Birth, CliError> {
+        let options = self.chart_options();
+        let birth = if Path::new(spec).is_file() {
+            people::read_person(Path::new(spec))?.birth(gazetteer, &options)?
+        } else if Birth::is_spec(spec) {
+            Birth::from_spec(spec, gazetteer, self.dst.into(), &options)?
+        } else {
+            let db = self.people()?;
+            db.persons[db.find(spec.trim())?].birth(gazetteer, &options)?
         };
+        warn(&birth);
+        Ok(birth)
+    }
+}
+
+fn main() -> ExitCode {
+    let matches = Args::command().get_matches();
+    let mut args = Args::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+    if let Some((name, _)) = matches.subcommand() {
+        // Chart options before a subcommand would be silently ignored
+        let chart_ids = ["date", "time", "birth", "tz", "location", "transit", "transit_at"];
+        if let Some(id) = chart_ids.iter().find(|id| matches.value_source(id) == Some(ValueSource::CommandLine)) {
+            Args::command()
+                .error(ErrorKind::ArgumentConflict, format!(
+                    "--{} is a chart option and cannot be used before the subcommand '{}'", id.replace('_', "-"), name))
+                .exit();
+        }
+    }
+    match apply_config(&mut args, &matches).and_then(|()| run(&args)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("hd: error: {}", e);
+            if matches!(e, CliError::Hd(HdError::AmbiguousLocalTime(_) | HdError::NonexistentLocalTime(_))) {
+                eprintln!("hd: hint: pass --dst earlier or --dst later to pick an offset");
+            }
+            if matches!(e, CliError::Hd(HdError::UnknownLocation(_) | HdError::AmbiguousLocation { .. })) {
+                eprintln!("hd: hint: qualify the location (e.g. \"London, Canada\") or pass --tz");
+            }
+            if matches!(&e, CliError::Hd(HdError::Ephemeris(msg)) if msg.contains("seas_18.se1")) {
+                eprintln!("hd: hint: pass --ephe-path DIR with the Swiss Ephemeris file seas_18.se1");
+            }
+            ExitCode::from(e.exit_code())
+        }
+    }
+}
 
-        display::print_transits(&overlay);
+/// Take global options not given on the command line from the config file,
+/// then apply the color choice
+fn apply_config(args: &mut Args, matches: &ArgMatches) -> Result<(), CliError> {
+    let config = Config::load(args.config.as_deref())?;
+    // Global options may be given at any level of subcommands
+    let given = |id: &str| {
+        let mut level = Some(matches);
+        while let Some(m) = level {
+            if m.value_source(id) == Some(ValueSource::CommandLine) {
+                return true;
+            }
+            level = m.subcommand().map(|(_, sub)| sub);
+        }
+        false
+    };
+    fn value<T: ValueEnum>(config: &Config, key: &str, v: &str) -> Result<T, ConfigError> {
+        T::from_str(v, true).map_err(|_| config.invalid(key, v))
+    }
+
+    if let (Some(v), false) = (&config.format, given("format")) {
+        args.format = value(&config, "format", v)?;
+    }
+    if let (Some(v), false) = (&config.color, given("color")) {
+        args.color = value(&config, "color", v)?;
+    }
+    if let (Some(v), false) = (&config.node, given("node")) {
+        args.node = value(&config, "node", v)?;
+    }
+    if let (Some(v), false) = (&config.dst, given("dst")) {
+        args.dst = value(&config, "dst", v)?;
+    }
+    if let (Some(bodies), false) = (&config.bodies, given("bodies")) {
+        args.bodies = bodies.iter()
+            .map(|b| parse_body(b).map_err(|_| config.invalid("body", b)))
+            .collect::<Result<_, _>>()?;
+    }
+    args.gazetteer = args.gazetteer.take().or(config.gazetteer);
+    args.ephe_path = args.ephe_path.take().or(config.ephe_path);
+    args.people_db = args.people_db.take().or(config.people_db);
+
+    match args.color {
+        Color::Always => colored::control::set_override(true),
+        Color::Never => colored::control::set_override(false),
+        Color::Auto => {}
+    }
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), CliError> {
+    if let Some(path) = &args.ephe_path {
+        chart::set_ephemeris_path(path);
+    }
+    let gazetteer = match &args.gazetteer {
+        Some(path) => Gazetteer::load_geonames(path)?,
+        None => Gazetteer::bundled(),
+    };
+    match &args.command {
+        Some(Command::Composite { a, b }) => run_composite(args, &gazetteer, a, b),
+        Some(Command::Transits { from, to, gates_only, planets, ics }) => {
+            run_transits(args, from, to, *gates_only, planets, ics.as_deref())
+        }
+        Some(Command::Forecast { birth, from, to, planets, ics }) => {
+            run_forecast(args, &gazetteer, birth, from, to, planets, ics.as_deref())
+        }
+        Some(Command::Return { birth, planet, from, to }) => {
+            run_return(args, &gazetteer, birth, *planet, from.as_deref(), to.as_deref())
+        }
+        Some(Command::Rectify { date, from, to, tz, location }) => {
+            run_rectify(args, &gazetteer, date, from, to, tz.as_deref(), location)
+        }
+        Some(Command::Search { from, to, activations, hd_type, authority, channels, profile, limit }) => {
+            let query = search::Query {
+                activations: activations.iter()
+                    .map(|s| search::ActivationConstraint::parse(s))
+                    .collect::<Result<_, _>>()?,
+                hd_type: *hd_type,
+                authority: authority.clone(),
+                channels: channels.clone(),
+                profile: *profile,
+            };
+            run_search(args, &query, from, to, *limit)
+        }
+        Some(Command::Batch { file, jsonl }) => run_batch(args, &gazetteer, file, *jsonl),
+        Some(Command::Penta { members, group }) => run_penta(args, &gazetteer, members, group.as_deref()),
+        Some(Command::People { command }) => run_people(args, &gazetteer, command),
+        Some(Command::Chart(chart)) => run_chart(args, &gazetteer, chart),
+        Some(Command::Transit { at, birth }) => run_transit(args, &gazetteer, at, birth.as_deref()),
+        Some(Command::Svg { output, chart }) => run_svg(args, &gazetteer, chart, output),
+        None => run_chart(args, &gazetteer, &args.chart),
+    }
+}
+
+fn run_chart(args: &Args, gazetteer: &Gazetteer, chart: &ChartArgs) -> Result<(), CliError> {
+    let birth = resolve_chart_birth(args, gazetteer, chart)?;
+    show_chart(args, &birth, transit_instant(chart)?)
+}
+
+fn run_svg(args: &Args, gazetteer: &Gazetteer, chart: &ChartArgs, output: &str) -> Result<(), CliError> {
+    let birth = resolve_chart_birth(args, gazetteer, chart)?;
+    let analysis = types::analyze(&birth.chart)?;
+    let overlay = transit_instant(chart)?.map(|at| transit_overlay(&birth.chart, &analysis, at)).transpose()?;
+    let transit_gates = overlay.as_ref().map(|o| o.transit_positions.iter().map(|a| a.gate).collect::<HashSet<u8>>());
+    write_svg(output, &svg::generate_svg(&birth.chart, &analysis, transit_gates.as_ref()))
+}
+
+fn resolve_chart_birth(args: &Args, gazetteer: &Gazetteer, chart: &ChartArgs) -> Result<Birth, CliError> {
+    match (&chart.birth, &chart.date, &chart.time) {
+        (Some(person), _, _) => args.resolve_birth(gazetteer, person),
+        // Geocode the location; with an explicit --tz it is only informational
+        (None, Some(date), Some(time)) => {
+            let birth = Birth::resolve(date, time, chart.tz.as_deref(), Some(&chart.location), gazetteer, args.dst.into(), &args.chart_options())?;
+            warn(&birth);
+            Ok(birth)
+        }
+        _ => unreachable!("clap requires --date and --time without --birth"),
+    }
+}
+
+/// Report a location that was not geocoded because an explicit zone was used
+fn warn(birth: &Birth) {
+    if let Some(warning) = &birth.warning {
+        eprintln!("hd: warning: {}", warning);
+    }
+}
+
+/// Instant of the transit overlay asked for, if any
+fn transit_instant(chart: &ChartArgs) -> Result<Option<DateTime<Utc>>, CliError> {
+    Ok(match &chart.transit_at {
+        Some(s) => Some(parse::parse_instant(s, Utc::now())?),
+        None if chart.transit => Some(Utc::now()),
+        None => None,
+    })
+}
+
+/// Transit positions at an instant and the channels they complete in a chart
+fn transit_overlay(natal: &Chart, analysis: &ChartAnalysis, at: DateTime<Utc>) -> Result<transit::TransitOverlay, CliError> {
+    let transit_positions = chart::calculate_transit_at_with(at, natal.node_model)?;
+    let completed_channels = transit::overlay_transits(&natal.gates(), &transit_positions, &analysis.defined_channels);
+    Ok(transit::TransitOverlay { at, transit_positions, completed_channels })
+}
+
+/// Chart report of one birth, with the transit overlay and SVG if asked
+fn show_chart(args: &Args, birth: &Birth, transit_at: Option<DateTime<Utc>>) -> Result<(), CliError> {
+    let hd_chart = &birth.chart;
+    let analysis = types::analyze(hd_chart)?;
+    let overlay = transit_at.map(|at| transit_overlay(hd_chart, &analysis, at)).transpose()?;
+
+    match args.format {
+        Format::Text => {
+            display::print_header(&birth.date, &birth.time, &birth.tz_name, birth.utc_offset, &birth.location_label(), birth.place.as_ref(), hd_chart.node_model);
+            display::print_summary(&analysis);
+            display::print_variables(&analysis);
+            display::print_activations(hd_chart);
+            display::print_channels(&analysis);
+            display::print_centers(&analysis);
+            display::print_definition(&analysis);
+            display::print_circuitry(&analysis);
+            if let Some(overlay) = &overlay {
+                display::print_transits(overlay);
+            }
+        }
+        Format::Json | Format::Jsonl => {
+            let mut doc = ChartDocument::new(birth.info(), hd_chart, &analysis);
+            if let Some(overlay) = &overlay {
+                doc = doc.with_transit(overlay);
+            }
+            write_json(args.format, &doc)?;
+        }
     }
 
     // SVG output
     if let Some(path) = &args.svg {
-        let svg_content = svg::generate_svg(
-            &hd_chart,
-            &analysis,
-            transit_gate_set.as_ref(),
-        );
-        std::fs::write(path, &svg_content).expect("Failed to write SVG file");
-        eprintln!("SVG bodygraph written to: {}", path);
+        let transit_gates = overlay.as_ref().map(|o| o.transit_positions.iter().map(|a| a.gate).collect::<HashSet<u8>>());
+        write_svg(path, &svg::generate_svg(hd_chart, &analysis, transit_gates.as_ref()))?;
+    }
+
+    Ok(())
+}
+
+/// Transit positions alone, or overlaid on a natal chart
+fn run_transit(args: &Args, gazetteer: &Gazetteer, at: &str, birth: Option<&str>) -> Result<(), CliError> {
+    let at = parse::parse_instant(at, Utc::now())?;
+    let Some(birth) = birth else {
+        let node = args.node.into();
+        let transit_positions = chart::calculate_transit_at_with(at, node)?;
+        let completed_channels = transit::overlay_transits(&HashSet::new(), &transit_positions, &[]);
+        let overlay = transit::TransitOverlay { at, transit_positions, completed_channels };
+        return match args.format {
+            Format::Text => {
+                display::print_transits(&overlay);
+                Ok(())
+            }
+            Format::Json | Format::Jsonl => write_json(args.format, &TransitDocument::new(&overlay, node)),
+        };
+    };
+
+    let birth = args.resolve_birth(gazetteer, birth)?;
+    let analysis = types::analyze(&birth.chart)?;
+    let overlay = transit_overlay(&birth.chart, &analysis, at)?;
+    match args.format {
+        Format::Text => {
+            display::print_header(&birth.date, &birth.time, &birth.tz_name, birth.utc_offset, &birth.location_label(), birth.place.as_ref(), birth.chart.node_model);
+            display::print_transits(&overlay);
+        }
+        Format::Json | Format::Jsonl => {
+            write_json(args.format, &ChartDocument::new(birth.info(), &birth.chart, &analysis).with_transit(&overlay))?;
+        }
+    }
+    if let Some(path) = &args.svg {
+        let transit_gates: HashSet<u8> = overlay.transit_positions.iter().map(|a| a.gate).collect();
+        write_svg(path, &svg::generate_svg(&birth.chart, &analysis, Some(&transit_gates)))?;
+    }
+    Ok(())
+}
+
+fn run_composite(args: &Args, gazetteer: &Gazetteer, a: &str, b: &str) -> Result<(), CliError> {
+    let a = args.resolve_birth(gazetteer, a)?;
+    let b = args.resolve_birth(gazetteer, b)?;
+    let comp = composite::composite(&a.chart, &b.chart);
+
+    match args.format {
+        Format::Text => display::print_composite(&a, &b, &comp),
+        Format::Json | Format::Jsonl => write_json(args.format, &CompositeDocument::new(a.info(), b.info(), &comp, a.chart.node_model))?,
+    }
+
+    if let Some(path) = &args.svg {
+        write_svg(path, &svg::generate_composite_svg(&a.chart, &b.chart, &comp))?;
+    }
+    Ok(())
+}
+
+fn run_transits(args: &Args, from: &str, to: &str, gates_only: bool, planets: &[String], ics: Option<&str>) -> Result<(), CliError> {
+    let now = Utc::now();
+    let (from, to) = (parse::parse_instant(from, now)?, parse::parse_instant(to, now)?);
+    let node = args.node.into();
+    let mut timeline = ingress::ingresses(from, to, node)?;
+    timeline.retain(|i| (!gates_only || i.is_gate_change())
+        && (planets.is_empty() || planets.iter().any(|p| p.eq_ignore_ascii_case(i.planet))));
+
+    match args.format {
+        Format::Text => display::print_ingresses(from, to, node, &timeline),
+        Format::Json | Format::Jsonl => write_json(args.format, &TimelineDocument::new(from, to, &timeline, node))?,
+    }
+
+    if let Some(path) = ics {
+        write_ics(path, &ical::ingress_calendar(&timeline, now))?;
     }
+    Ok(())
+}
+
+fn run_forecast(
+    args: &Args,
+    gazetteer: &Gazetteer,
+    birth: &str,
+    from: &str,
+    to: &str,
+    planets: &[String],
+    ics: Option<&str>,
+) -> Result<(), CliError> {
+    let birth = args.resolve_birth(gazetteer, birth)?;
+    let analysis = types::analyze(&birth.chart)?;
+    let now = Utc::now();
+    let (from, to) = (parse::parse_instant(from, now)?, parse::parse_instant(to, now)?);
+    let mut windows = transit::forecast(&birth.chart.gates(), &analysis.defined_channels, &analysis.defined_centers, from, to, birth.chart.node_model)?;
+    windows.retain(|w| planets.is_empty() || planets.iter().any(|p| p.eq_ignore_ascii_case(w.planet)));
+
+    match args.format {
+        Format::Text => display::print_forecast(&birth, from, to, &windows),
+        Format::Json | Format::Jsonl => write_json(args.format, &ForecastDocument::new(birth.info(), from, to, &windows, birth.chart.node_model))?,
+    }
+
+    if let Some(path) = ics {
+        write_ics(path, &ical::forecast_calendar(&windows, now))?;
+    }
+    Ok(())
+}
+
+fn parse_body(s: &str) -> Result<ExtraBody, String> {
+    ExtraBody::from_name(s).ok_or_else(|| "expected chiron or lilith".to_string())
+}
+
+fn parse_type(s: &str) -> Result<HdType, String> {
+    HdType::from_name(s).ok_or_else(|| "expected generator, manifesting-generator, manifestor, projector or reflector".to_string())
+}
+
+fn parse_cycle(s: &str) -> Result<returns::Cycle, String> {
+    returns::Cycle::from_planet(s).ok_or_else(|| "expected sun, saturn, uranus or chiron".to_string())
+}
+
+fn run_return(
+    args: &Args,
+    gazetteer: &Gazetteer,
+    birth: &str,
+    cycle: returns::Cycle,
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<(), CliError> {
+    let birth = args.resolve_birth(gazetteer, birth)?;
+    let now = Utc::now();
+    let (default_from, default_to) = returns::default_range(cycle, birth.instant, now);
+    let from = from.map(|s| parse::parse_instant(s, now)).transpose()?.unwrap_or(default_from);
+    let to = to.map(|s| parse::parse_instant(s, now)).transpose()?.unwrap_or(default_to);
+    let found = returns::returns(cycle, birth.instant, &birth.chart, from, to)?;
+
+    match args.format {
+        Format::Text => display::print_returns(&birth, cycle, from, to, &found),
+        Format::Json | Format::Jsonl => write_json(args.format, &ReturnDocument::new(birth.info(), from, to, &found, birth.chart.node_model))?,
+    }
+
+    // Natal bodygraph with the first return's personality gates overlaid
+    if let (Some(path), Some(first)) = (&args.svg, found.first()) {
+        let analysis = types::analyze(&birth.chart)?;
+        let return_gates: HashSet<u8> = first.chart.personality.iter().map(|a| a.gate).collect();
+        write_svg(path, &svg::generate_svg(&birth.chart, &analysis, Some(&return_gates)))?;
+    }
+    Ok(())
+}
+
+fn run_rectify(
+    args: &Args,
+    gazetteer: &Gazetteer,
+    date: &str,
+    from: &str,
+    to: &str,
+    tz: Option<&str>,
+    location: &str,
+) -> Result<(), CliError> {
+    let options = args.chart_options();
+    let start = Birth::resolve(date, from, tz, Some(location), gazetteer, args.dst.into(), &options)?;
+    let end = Birth::resolve(date, to, tz, Some(location), gazetteer, args.dst.into(), &options)?;
+    warn(&start);
+    let result = rectify::rectify(start.instant, end.instant, &options)?;
+
+    match args.format {
+        Format::Text => display::print_rectification(&start, &end, &result),
+        Format::Json | Format::Jsonl => write_json(args.format, &RectifyDocument::new(start.info(), &result, options.node_model))?,
+    }
+    Ok(())
+}
+
+fn run_search(args: &Args, query: &search::Query, from: &str, to: &str, limit: Option<usize>) -> Result<(), CliError> {
+    let now = Utc::now();
+    let (from, to) = (parse::parse_instant(from, now)?, parse::parse_instant(to, now)?);
+    let node = args.node.into();
+    let mut matches = search::search(query, from, to, node)?;
+    if let Some(limit) = limit {
+        matches.truncate(limit);
+    }
+
+    match args.format {
+        Format::Text => display::print_search(query, from, to, node, &matches),
+        Format::Json | Format::Jsonl => write_json(args.format, &SearchDocument::new(from, to, &query.activations, &matches, node))?,
+    }
+    Ok(())
+}
+
+fn run_batch(args: &Args, gazetteer: &Gazetteer, file: &str, jsonl: bool) -> Result<(), CliError> {
+    let text = if file == "-" {
+        std::io::read_to_string(std::io::stdin())
+    } else {
+        std::fs::read_to_string(file)
+    }.map_err(|e| CliError::Read(file.to_string(), e))?;
+    let jsonl = jsonl || file.ends_with(".jsonl") || file.ends_with(".ndjson");
+    let rows = batch::read_records(&text, jsonl).map_err(|e| CliError::Batch(file.to_string(), e))?;
+    let outcomes = batch::compute(&rows, gazetteer, args.dst.into(), &args.chart_options());
+
+    match args.format {
+        Format::Text => display::print_batch(&outcomes),
+        Format::Json => write_json(args.format, &outcomes.iter().map(batch::Entry::new).collect::<Vec<_>>())?,
+        Format::Jsonl => {
+            for outcome in &outcomes {
+                write_json(args.format, &batch::Entry::new(outcome))?;
+            }
+        }
+    }
+
+    // Text output counts the failures itself; keep machine output clean
+    let failed = outcomes.iter().filter(|o| o.chart.is_err()).count();
+    if failed > 0 && args.format != Format::Text {
+        eprintln!("hd: {} of {} records failed", failed, outcomes.len());
+    }
+    Ok(())
+}
+
+fn run_penta(args: &Args, gazetteer: &Gazetteer, specs: &[String], group: Option<&str>) -> Result<(), CliError> {
+    let mut members = specs.iter()
+        .map(|spec| args.resolve_birth(gazetteer, spec))
+        .collect::<Result<Vec<_>, _>>()?;
+    if let Some(group) = group {
+        for person in args.people()?.persons.iter().filter(|p| p.in_group(group)) {
+            let birth = person.birth(gazetteer, &args.chart_options())?;
+            warn(&birth);
+            members.push(birth);
+        }
+    }
+    let charts: Vec<_> = members.iter().map(|m| m.chart.clone()).collect();
+    let group = penta::penta(&charts)?;
+
+    match args.format {
+        Format::Text => display::print_penta(&members, &group),
+        Format::Json | Format::Jsonl => write_json(args.format, &PentaDocument::new(members.iter().map(Birth::info).collect(), &group, args.node.into()))?,
+    }
+
+    if let Some(path) = &args.svg {
+        write_svg(path, &svg::generate_penta_svg(&group))?;
+    }
+    Ok(())
+}
+
+fn run_people(args: &Args, gazetteer: &Gazetteer, command: &PeopleCommand) -> Result<(), CliError> {
+    let mut db = args.people()?;
+    match command {
+        PeopleCommand::Add { name, date, time, tz, location, groups } => {
+            // Without a zone or location the default location is used, as for a chart
+            let location = location.as_deref().or(tz.is_none().then_some(birth::DEFAULT_LOCATION));
+            let birth = Birth::resolve(date, time, tz.as_deref(), location, gazetteer, args.dst.into(), &args.chart_options())?;
+            warn(&birth);
+            let mut person = people::Person::from_birth(name, &birth, db.new_id())?;
+            person.groups = groups.clone();
+            let added = db.add(person)?;
+            eprintln!("Saved {} ({})", added.data.name, added.data.id);
+            db.save()?;
+        }
+        PeopleCommand::List { group } => {
+            let mut refreshed = false;
+            for person in db.persons.iter_mut().filter(|p| group.as_deref().is_none_or(|g| p.in_group(g))) {
+                refreshed |= person.summary(gazetteer, args.node.into())?.1;
+            }
+            if refreshed {
+                db.save()?;
+            }
+            let listed: Vec<&people::Person> = db.persons.iter()
+                .filter(|p| group.as_deref().is_none_or(|g| p.in_group(g)))
+                .collect();
+            match args.format {
+                Format::Text => display::print_people(&listed),
+                Format::Json | Format::Jsonl => write_json(args.format, &listed)?,
+            }
+        }
+        PeopleCommand::Show { person } => {
+            let birth = db.persons[db.find(person)?].birth(gazetteer, &args.chart_options())?;
+            show_chart(args, &birth, None)?;
+        }
+        PeopleCommand::Rm { person } => {
+            let removed = db.persons.remove(db.find(person)?);
+            eprintln!("Removed {} ({})", removed.data.name, removed.data.id);
+            db.save()?;
+        }
+        PeopleCommand::Edit { person, name, date, time, tz, location, groups, ungroups } => {
+            let index = db.find(person)?;
+            if let Some(name) = name {
+                if db.persons.iter().enumerate().any(|(i, p)| i != index && p.data.name.eq_ignore_ascii_case(name)) {
+                    return Err(PeopleError::Duplicate(name.clone()).into());
+                }
+            }
+            let p = &mut db.persons[index];
+            if date.is_some() || time.is_some() || tz.is_some() || location.is_some() {
+                // A new location brings its own zone unless --tz is given
+                let tz = tz.clone().or_else(|| match location {
+                    Some(_) => None,
+                    None => Some(p.tz.clone().unwrap_or_else(|| format!("{:+}", p.data.tz_offset))),
+                });
+                let location = location.clone().or_else(|| p.location.clone());
+                let birth = Birth::resolve(date.as_deref().unwrap_or(&p.date()), time.as_deref().unwrap_or(&p.time()),
+                    tz.as_deref(), location.as_deref(), gazetteer, args.dst.into(), &args.chart_options())?;
+                warn(&birth);
+                let updated = people::Person::from_birth(&p.data.name, &birth, p.data.id.clone())?;
+                p.data = people::PersonData { shared_by: p.data.shared_by.take(), ..updated.data };
+                (p.tz, p.location, p.cached) = (updated.tz, updated.location, updated.cached);
+            }
+            if let Some(name) = name {
+                p.data.name = name.clone();
+            }
+            for group in groups {
+                if !p.in_group(group) {
+                    p.groups.push(group.clone());
+                }
+            }
+            p.groups.retain(|g| !ungroups.iter().any(|u| u.eq_ignore_ascii_case(g)));
+            eprintln!("Updated {} ({})", p.data.name, p.data.id);
+            db.save()?;
+        }
+        PeopleCommand::Import { file } => {
+            let persons = people::read_person_data(Path::new(file))?;
+            let total = persons.len();
+            let added = db.import(persons);
+            eprintln!("Imported {} people ({} new, {} updated)", total, added, total - added);
+            db.save()?;
+        }
+        PeopleCommand::Export { file, group } => {
+            let persons: Vec<&people::PersonData> = db.persons.iter()
+                .filter(|p| group.as_deref().is_none_or(|g| p.in_group(g)))
+                .map(|p| &p.data)
+                .collect();
+            match file {
+                Some(path) => {
+                    let json = serde_json::to_string_pretty(&persons).map_err(CliError::Json)?;
+                    std::fs::write(path, json + "\n").map_err(|e| CliError::Io(path.clone(), e))?;
+                    eprintln!("{} people written to: {}", persons.len(), path);
+                }
+                None => write_json(Format::Json, &persons)?,
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Write a document to stdout, pretty-printed or on one line for JSONL
+fn write_json<T: serde::Serialize>(format: Format, doc: &T) -> Result<(), CliError> {
+    let mut out = std::io::stdout().lock();
+    match format {
+        Format::Jsonl => serde_json::to_writer(&mut out, doc),
+        _ => serde_json::to_writer_pretty(&mut out, doc),
+    }.map_err(CliError::Json)?;
+    writeln!(out).map_err(|e| CliError::Io("stdout".to_string(), e))
+}
+
+fn write_ics(path: &str, content: &str) -> Result<(), CliError> {
+    std::fs::write(path, content).map_err(|e| CliError::Io(path.to_string(), e))?;
+    eprintln!("Calendar written to: {}", path);
+    Ok(())
+}
+
+fn write_svg(path: &str, content: &str) -> Result<(), CliError> {
+    std::fs::write(path, content).map_err(|e| CliError::Io(path.to_string(), e))?;
+    eprintln!("SVG bodygraph written to: {}", path);
+    Ok(())
 }
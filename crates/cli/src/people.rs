@@ -0,0 +1,369 @@
+//! Local people database, so birth data is typed once and then used by name.
+//!
+//! The database is a JSON array under the XDG data directory. Each entry is
+//! the web app's `PersonData` plus CLI fields (zone, location, groups and a
+//! cached chart summary), so the file itself reads as a web app person list.
+
+use hd_core::{types, ChartOptions, DstPolicy, Gazetteer, HdError, NodeModel};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use crate::birth::Birth;
+
+/// Person as the web app stores it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonData {
+    pub id: String,
+    pub name: String,
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    /// UTC offset in hours at the birth moment
+    pub tz_offset: f64,
+    /// npub or pubkey of whoever shared the data
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_by: Option<String>,
+}
+
+/// Saved person: web app data plus what only the CLI keeps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(flatten)]
+    pub data: PersonData,
+    /// Zone as given or resolved from the location; `tzOffset` is what the
+    /// chart uses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tz: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached: Option<ChartSummary>,
+}
+
+/// Chart properties kept with a person so listing needs no recalculation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSummary {
+    pub node_model: NodeModel,
+    #[serde(rename = "type")]
+    pub hd_type: String,
+    pub profile: String,
+    pub authority: String,
+    pub definition: String,
+}
+
+impl Person {
+    /// Person from a resolved birth, with a fresh id
+    pub fn from_birth(name: &str, birth: &Birth, id: String) -> Result<Person, HdError> {
+        let (year, month, day) = hd_core::parse::parse_date(&birth.date)?;
+        let hour = hd_core::parse::parse_time(&birth.time)?;
+        let minutes = (hour * 60.0).round() as u32;
+        let mut person = Person {
+            data: PersonData {
+                id,
+                name: name.to_string(),
+                year,
+                month,
+                day,
+                hour: minutes / 60,
+                minute: minutes % 60,
+                tz_offset: birth.utc_offset,
+                shared_by: None,
+            },
+            tz: Some(birth.tz_name.clone()),
+            location: Some(birth.location.clone()).filter(|l| !l.is_empty()),
+            groups: Vec::new(),
+            cached: None,
+        };
+        person.cached = Some(ChartSummary::of(birth)?);
+        Ok(person)
+    }
+
+    pub fn date(&self) -> String {
+        format!("{:04}-{:02}-{:02}", self.data.year, self.data.month, self.data.day)
+    }
+
+    pub fn time(&self) -> String {
+        format!("{:02}:{:02}", self.data.hour, self.data.minute)
+    }
+
+    /// Calculate the chart. The stored offset is used as is, so a zone's
+    /// DST choice made when the person was added is kept.
+    pub fn birth(&self, gazetteer: &Gazetteer, options: &ChartOptions) -> Result<Birth, HdError> {
+        let offset = format!("{:+}", self.data.tz_offset);
+        let mut birth = Birth::resolve(&self.date(), &self.time(), Some(&offset), self.location.as_deref(),
+            gazetteer, DstPolicy::Reject, options)?;
+        if let Some(tz) = &self.tz {
+            birth.tz_name = tz.clone();
+        }
+        Ok(birth)
+    }
+
+    /// Summary for the node model, from the cache or freshly calculated;
+    /// true when the cache was refreshed
+    pub fn summary(&mut self, gazetteer: &Gazetteer, node_model: NodeModel) -> Result<(&ChartSummary, bool), HdError> {
+        let stale = self.cached.as_ref().is_none_or(|c| c.node_model != node_model);
+        if stale {
+            let options = ChartOptions { node_model, ..ChartOptions::default() };
+            self.cached = Some(ChartSummary::of(&self.birth(gazetteer, &options)?)?);
+        }
+        Ok((self.cached.as_ref().expect("summary just cached"), stale))
+    }
+
+    pub fn in_group(&self, group: &str) -> bool {
+        self.groups.iter().any(|g| g.eq_ignore_ascii_case(group))
+    }
+}
+
+impl ChartSummary {
+    pub fn of(birth: &Birth) -> Result<ChartSummary, HdError> {
+        let analysis = types::analyze(&birth.chart)?;
+        Ok(ChartSummary {
+            node_model: birth.chart.node_model,
+            hd_type: analysis.hd_type.name().to_string(),
+            profile: format!("{}/{}", analysis.profile.0, analysis.profile.1),
+            authority: analysis.authority.to_string(),
+            definition: analysis.definition.name().to_string(),
+        })
+    }
+}
+
+/// Failure to find, read or update people
+#[derive(Debug)]
+pub enum PeopleError {
+    /// No person with this id or name
+    Unknown(String),
+    /// Several people share the name; their ids are listed
+    Ambiguous(String, Vec<String>),
+    /// A person with this name already exists
+    Duplicate(String),
+    /// Neither XDG_DATA_HOME nor HOME is set
+    NoDataDir,
+    Io(PathBuf, std::io::Error),
+    Json(PathBuf, serde_json::Error),
+}
+
+impl std::fmt::Display for PeopleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PeopleError::Unknown(who) => write!(f, "no saved person '{}'", who),
+            PeopleError::Ambiguous(who, ids) => write!(f, "several people are named '{}'; use an id: {}", who, ids.join(", ")),
+            PeopleError::Duplicate(name) => write!(f, "a person named '{}' is already saved", name),
+            PeopleError::NoDataDir => write!(f, "cannot locate the data directory; set XDG_DATA_HOME or pass --people-db"),
+            PeopleError::Io(path, e) => write!(f, "cannot access '{}': {}", path.display(), e),
+            PeopleError::Json(path, e) => write!(f, "cannot read people from '{}': {}", path.display(), e),
+        }
+    }
+}
+
+/// The people database file and its contents
+pub struct People {
+    path: PathBuf,
+    pub persons: Vec<Person>,
+}
+
+impl People {
+    /// `$XDG_DATA_HOME/hd/people.json`, else `~/.local/share/hd/people.json`
+    pub fn default_path() -> Result<PathBuf, PeopleError> {
+        let data = std::env::var_os("XDG_DATA_HOME").filter(|d| !d.is_empty()).map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+            .ok_or(PeopleError::NoDataDir)?;
+        Ok(data.join("hd").join("people.json"))
+    }
+
+    /// Open the database; a missing file is an empty database
+    pub fn open(path: PathBuf) -> Result<People, PeopleError> {
+        let persons = match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| PeopleError::Json(path.clone(), e))?,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(PeopleError::Io(path, e)),
+        };
+        Ok(People { path, persons })
+    }
+
+    /// Write the database, replacing the file only once fully written
+    pub fn save(&self) -> Result<(), PeopleError> {
+        let io = |e| PeopleError::Io(self.path.clone(), e);
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(io)?;
+        }
+        let json = serde_json::to_string_pretty(&self.persons).map_err(|e| PeopleError::Json(self.path.clone(), e))?;
+        let tmp = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp, json + "\n").map_err(io)?;
+        std::fs::rename(&tmp, &self.path).map_err(io)
+    }
+
+    /// Index of the person with this id, else the only one with this name
+    /// (ignoring case)
+    pub fn find(&self, who: &str) -> Result<usize, PeopleError> {
+        if let Some(i) = self.persons.iter().position(|p| p.data.id == who) {
+            return Ok(i);
+        }
+        let named: Vec<usize> = self.persons.iter().enumerate()
+            .filter(|(_, p)| p.data.name.eq_ignore_ascii_case(who))
+            .map(|(i, _)| i)
+            .collect();
+        match named.as_slice() {
+            [i] => Ok(*i),
+            [] => Err(PeopleError::Unknown(who.to_string())),
+            _ => Err(PeopleError::Ambiguous(who.to_string(), named.iter().map(|&i| self.persons[i].data.id.clone()).collect())),
+        }
+    }
+
+    /// Add a person whose name is not taken yet
+    pub fn add(&mut self, person: Person) -> Result<&Person, PeopleError> {
+        if self.persons.iter().any(|p| p.data.name.eq_ignore_ascii_case(&person.data.name)) {
+            return Err(PeopleError::Duplicate(person.data.name));
+        }
+        self.persons.push(person);
+        Ok(self.persons.last().expect("just added"))
+    }
+
+    /// Add web app persons, replacing saved ones with the same id; returns
+    /// how many were new
+    pub fn import(&mut self, persons: Vec<PersonData>) -> usize {
+        let mut added = 0;
+        for data in persons {
+            match self.persons.iter_mut().find(|p| p.data.id == data.id) {
+                Some(p) => {
+                    // The web app has no zone name; a changed offset voids it
+                    if p.data.tz_offset != data.tz_offset {
+                        p.tz = None;
+                    }
+                    p.data = data;
+                    p.cached = None;
+                }
+                None => {
+                    self.persons.push(Person { data, tz: None, location: None, groups: Vec::new(), cached: None });
+                    added += 1;
+                }
+            }
+        }
+        added
+    }
+
+    /// A new id in the web app's style: 7 base-36 characters
+    pub fn new_id(&self) -> String {
+        loop {
+            let mut n = RandomState::new().build_hasher().finish();
+            let id: String = (0..7).map(|_| {
+                let digit = (n % 36) as u32;
+                n /= 36;
+                char::from_digit(digit, 36).expect("digit below 36")
+            }).collect();
+            if !self.persons.iter().any(|p| p.data.id == id) {
+                return id;
+            }
+        }
+    }
+}
+
+/// Read web app persons from JSON: an array, a single person, or a saved
+/// people database
+pub fn read_person_data(path: &Path) -> Result<Vec<PersonData>, PeopleError> {
+    let text = std::fs::read_to_string(path).map_err(|e| PeopleError::Io(path.to_path_buf(), e))?;
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        Many(Vec<PersonData>),
+        One(PersonData),
+    }
+    match serde_json::from_str(&text).map_err(|e| PeopleError::Json(path.to_path_buf(), e))? {
+        OneOrMany::Many(persons) => Ok(persons),
+        OneOrMany::One(person) => Ok(vec![person]),
+    }
+}
+
+/// Read one person from a JSON file, keeping CLI fields when present
+pub fn read_person(path: &Path) -> Result<Person, PeopleError> {
+    let text = std::fs::read_to_string(path).map_err(|e| PeopleError::Io(path.to_path_buf(), e))?;
+    serde_json::from_str(&text).map_err(|e| PeopleError::Json(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn data(id: &str, name: &str, tz_offset: f64) -> PersonData {
+        PersonData { id: id.to_string(), name: name.to_string(), year: 1985, month: 6, day: 15, hour: 14, minute: 30, tz_offset, shared_by: None }
+    }
+
+    fn person(id: &str, name: &str) -> Person {
+        Person { data: data(id, name, 3.0), tz: Some("Europe/Helsinki".to_string()), location: None, groups: Vec::new(), cached: None }
+    }
+
+    fn people(persons: Vec<Person>) -> People {
+        People { path: PathBuf::new(), persons }
+    }
+
+    #[test]
+    fn find_prefers_ids_then_unique_names() {
+        let db = people(vec![person("abc1234", "Ann"), person("def5678", "abc1234"), person("ghi9012", "Bob"), person("jkl3456", "bob")]);
+        assert_eq!(db.find("abc1234").unwrap(), 0);
+        assert_eq!(db.find("ANN").unwrap(), 0);
+        assert_eq!(db.find("def5678").unwrap(), 1);
+        match db.find("Bob") {
+            Err(PeopleError::Ambiguous(who, ids)) => assert_eq!((who.as_str(), ids), ("Bob", vec!["ghi9012".to_string(), "jkl3456".to_string()])),
+            other => panic!("expected an ambiguous name, got {:?}", other.map(|i| &db.persons[i].data.name)),
+        }
+        assert!(matches!(db.find("Cid"), Err(PeopleError::Unknown(who)) if who == "Cid"));
+    }
+
+    #[test]
+    fn import_replaces_by_id_and_drops_stale_zones() {
+        let mut db = people(vec![person("abc1234", "Ann"), person("def5678", "Bob")]);
+        db.persons[1].cached = Some(ChartSummary {
+            node_model: NodeModel::True,
+            hd_type: "Generator".to_string(),
+            profile: "1/3".to_string(),
+            authority: "Sacral".to_string(),
+            definition: "Single Definition".to_string(),
+        });
+        let added = db.import(vec![data("abc1234", "Ann", 2.0), data("def5678", "Robert", 3.0), data("ghi9012", "Cid", 1.0)]);
+        assert_eq!(added, 1);
+        assert_eq!(db.persons.len(), 3);
+        // The offset changed, so the zone may no longer match it
+        assert_eq!((db.persons[0].data.tz_offset, db.persons[0].tz.as_deref()), (2.0, None));
+        assert_eq!((db.persons[1].data.name.as_str(), db.persons[1].tz.as_deref()), ("Robert", Some("Europe/Helsinki")));
+        assert!(db.persons[1].cached.is_none());
+        assert_eq!(db.persons[2].data.id, "ghi9012");
+    }
+
+    #[test]
+    fn person_files_hold_one_person_or_a_list() {
+        let dir = std::env::temp_dir().join(format!("hd-people-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (one, many) = (dir.join("one.json"), dir.join("many.json"));
+        std::fs::write(&one, serde_json::to_string(&data("abc1234", "Ann", 3.0)).unwrap()).unwrap();
+        std::fs::write(&many, serde_json::to_string(&[person("abc1234", "Ann"), person("def5678", "Bob")]).unwrap()).unwrap();
+
+        let read = read_person_data(&one).unwrap();
+        assert_eq!((read.len(), read[0].name.as_str(), read[0].tz_offset), (1, "Ann", 3.0));
+        let read = read_person_data(&many).unwrap();
+        assert_eq!(read.iter().map(|p| p.id.as_str()).collect::<Vec<_>>(), ["abc1234", "def5678"]);
+        assert!(matches!(read_person_data(&dir.join("missing.json")), Err(PeopleError::Io(..))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn birth_uses_a_fractional_offset_as_stored() {
+        let mut ann = person("abc1234", "Ann");
+        ann.data.tz_offset = 5.5;
+        ann.tz = None;
+        let birth = ann.birth(&Gazetteer::bundled(), &ChartOptions::default()).unwrap();
+        assert_eq!(birth.utc_offset, 5.5);
+        assert_eq!(birth.instant, Utc.with_ymd_and_hms(1985, 6, 15, 9, 0, 0).unwrap());
+        assert_eq!((birth.date.as_str(), birth.time.as_str()), ("1985-06-15", "14:30"));
+
+        ann.tz = Some("Asia/Kolkata".to_string());
+        assert_eq!(ann.birth(&Gazetteer::bundled(), &ChartOptions::default()).unwrap().tz_name, "Asia/Kolkata");
+    }
+}
@@ -0,0 +1,69 @@
+use std::process::{Command, Output};
+
+fn hd(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_hd-cli"))
+        .args(args)
+        // Keep a developer's own config and people out of the tests
+        .env("XDG_CONFIG_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .env("XDG_DATA_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .output()
+        .unwrap()
+}
+
+/// Exit code and stderr, checking the failure was reported rather than a panic
+fn failure(args: &[&str]) -> (i32, String) {
+    let out = hd(args);
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(!stderr.contains("panicked"), "{stderr}");
+    (out.status.code().expect("exited normally"), stderr)
+}
+
+#[test]
+fn invalid_date_exits_with_input_error() {
+    let (code, stderr) = failure(&["--date", "1985-13-01", "--time", "14:30"]);
+    assert_eq!(code, 2);
+    assert!(stderr.starts_with("hd: error: invalid date '1985-13-01'"), "{stderr}");
+}
+
+#[test]
+fn unknown_timezone_exits_with_input_error() {
+    let (code, stderr) = failure(&["--date", "1985-06-15", "--time", "14:30", "--tz", "Mars/Olympus"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("Mars/Olympus"), "{stderr}");
+}
+
+#[test]
+fn unknown_location_exits_with_input_error_and_hint() {
+    let (code, stderr) = failure(&["--date", "1985-06-15", "--time", "14:30", "--location", "Atlantis"]);
+    assert_eq!(code, 2);
+    assert!(stderr.contains("unknown location 'Atlantis'"), "{stderr}");
+    assert!(stderr.contains("hd: hint:"), "{stderr}");
+}
+
+#[test]
+fn date_out_of_range_exits_with_its_own_code() {
+    let (code, _) = failure(&["--date", "9999-01-01", "--time", "12:00", "--tz", "0"]);
+    assert_eq!(code, 3);
+}
+
+#[test]
+fn valid_chart_exits_successfully() {
+    let out = hd(&["--date", "1985-06-15", "--time", "14:30", "--tz", "+3", "--format", "json"]);
+    assert!(out.status.success());
+}
+
+#[test]
+fn unknown_location_with_a_zone_only_warns() {
+    let out = hd(&["--date", "1985-06-15", "--time", "14:30", "--tz", "+3", "--location", "Atlantis", "--format", "json"]);
+    assert!(out.status.success());
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert_eq!(stderr, "hd: warning: unknown location 'Atlantis'\n");
+
+    // Batch records keep their warning in the output instead
+    let file = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("warning-batch.csv");
+    std::fs::write(&file, "date,time,tz,location\n1985-06-15,14:30,+3,Atlantis\n").unwrap();
+    let out = hd(&["batch", file.to_str().unwrap(), "--format", "jsonl"]);
+    assert!(out.status.success());
+    assert!(out.stderr.is_empty(), "{}", String::from_utf8_lossy(&out.stderr));
+    assert!(String::from_utf8(out.stdout).unwrap().contains("\"warning\":\"unknown location 'Atlantis'\""));
+}
@@ -0,0 +1,41 @@
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn hd(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_hd-cli"))
+        .args(args)
+        // Keep a developer's own config and people out of the tests
+        .env("XDG_CONFIG_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .env("XDG_DATA_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .output()
+        .unwrap()
+}
+
+fn json(out: Output) -> serde_json::Value {
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    serde_json::from_slice(&out.stdout).unwrap()
+}
+
+/// Ids are base-36, so about one in four starts with a digit
+#[test]
+fn ids_starting_with_a_digit_find_the_person() {
+    let db = Path::new(env!("CARGO_TARGET_TMPDIR")).join("digit-ids.json");
+    std::fs::write(&db, r#"[
+        {"id": "7k2x9qa", "name": "Ann", "year": 1985, "month": 6, "day": 15, "hour": 14, "minute": 30, "tzOffset": 3.0},
+        {"id": "3bq0zz1", "name": "Bob", "year": 1990, "month": 1, "day": 2, "hour": 8, "minute": 0, "tzOffset": -5.0}
+    ]"#).unwrap();
+    let db = db.to_str().unwrap();
+
+    let by_id = json(hd(&["chart", "--birth", "7k2x9qa", "--people-db", db, "--format", "json"]));
+    let by_spec = json(hd(&["chart", "--birth", "1985-06-15 14:30 +3", "--people-db", db, "--format", "json"]));
+    assert_eq!(by_id["chart"], by_spec["chart"]);
+
+    let composite = json(hd(&["composite", "--a", "7k2x9qa", "--b", "3bq0zz1", "--people-db", db, "--format", "json"]));
+    assert_eq!(composite["a"]["date"], "1985-06-15");
+    assert_eq!(composite["b"]["date"], "1990-01-02");
+
+    // A date that does not parse is still reported as one
+    let out = hd(&["chart", "--birth", "1985-13-01 14:30", "--people-db", db]);
+    assert_eq!(out.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&out.stderr).contains("invalid date"));
+}
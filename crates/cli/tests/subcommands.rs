@@ -0,0 +1,119 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const BIRTH: [&str; 6] = ["--date", "1985-06-15", "--time", "14:30", "--tz", "+3"];
+
+/// Run hd with its config read from `config_home`
+fn hd_with(config_home: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_hd-cli"))
+        .args(args)
+        .env("XDG_CONFIG_HOME", config_home)
+        .env("XDG_DATA_HOME", env!("CARGO_TARGET_TMPDIR"))
+        .output()
+        .unwrap()
+}
+
+fn hd(args: &[&str]) -> Output {
+    hd_with(Path::new(env!("CARGO_TARGET_TMPDIR")), args)
+}
+
+fn stdout(out: Output) -> String {
+    assert!(out.status.success(), "{}", String::from_utf8_lossy(&out.stderr));
+    String::from_utf8(out.stdout).unwrap()
+}
+
+fn json(out: Output) -> serde_json::Value {
+    serde_json::from_str(&stdout(out)).unwrap()
+}
+
+/// An empty directory for files written by one test
+fn scratch(name: &str) -> PathBuf {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn chart_options_without_a_subcommand_run_chart() {
+    let old = stdout(hd(&[&BIRTH[..], &["--format", "json"]].concat()));
+    let new = stdout(hd(&[&["chart"], &BIRTH[..], &["--format", "json"]].concat()));
+    assert_eq!(old, new);
+
+    let old = stdout(hd(&[&BIRTH[..], &["--transit-at", "2024-01-01T00:00Z", "--format", "json"]].concat()));
+    let new = stdout(hd(&[&["chart"], &BIRTH[..], &["--transit-at", "2024-01-01T00:00Z", "--format", "json"]].concat()));
+    assert_eq!(old, new);
+}
+
+#[test]
+fn transit_overlay_flag_matches_the_transit_subcommand() {
+    let old = json(hd(&[&BIRTH[..], &["--transit-at", "2024-01-01T00:00Z", "--format", "json"]].concat()));
+    let new = json(hd(&["transit", "--at", "2024-01-01T00:00Z", "--birth", "1985-06-15 14:30 +3", "--format", "json"]));
+    assert!(old.get("transit").is_some());
+    assert_eq!(old["transit"], new["transit"]);
+    assert_eq!(old["chart"], new["chart"]);
+}
+
+#[test]
+fn svg_flag_matches_the_svg_subcommand() {
+    let dir = scratch("svg-mapping");
+    let (old, new) = (dir.join("old.svg"), dir.join("new.svg"));
+    stdout(hd(&[&BIRTH[..], &["--svg", old.to_str().unwrap()]].concat()));
+    stdout(hd(&[&["svg", new.to_str().unwrap()], &BIRTH[..]].concat()));
+    let svg = std::fs::read_to_string(&old).unwrap();
+    assert!(svg.starts_with("<svg") || svg.starts_with("<?xml"), "{}", &svg[..40.min(svg.len())]);
+    assert_eq!(svg, std::fs::read_to_string(&new).unwrap());
+}
+
+#[test]
+fn global_options_apply_before_and_after_the_subcommand() {
+    let before = json(hd(&[&["--format", "json", "--node", "mean", "chart"], &BIRTH[..]].concat()));
+    let after = json(hd(&[&["chart"], &BIRTH[..], &["--format", "json", "--node", "mean"]].concat()));
+    assert_eq!(before["nodeModel"], "mean");
+    assert_eq!(before, after);
+}
+
+#[test]
+fn chart_options_before_a_subcommand_are_rejected() {
+    let out = hd(&["--date", "1985-06-15", "transit"]);
+    assert_eq!(out.status.code(), Some(2));
+    let stderr = String::from_utf8(out.stderr).unwrap();
+    assert!(stderr.contains("--date is a chart option"), "{stderr}");
+}
+
+#[test]
+fn config_file_sets_defaults_that_flags_override() {
+    let home = scratch("config-defaults");
+    std::fs::create_dir_all(home.join("hd")).unwrap();
+    std::fs::write(home.join("hd").join("config.json"), r#"{"format": "json", "node": "mean"}"#).unwrap();
+
+    // Read from the default location, for old flags and subcommands alike
+    let doc = json(hd_with(&home, &BIRTH));
+    assert_eq!(doc["nodeModel"], "mean");
+    assert_eq!(json(hd_with(&home, &[&["chart"], &BIRTH[..]].concat())), doc);
+
+    // Flags win over the file
+    let doc = json(hd_with(&home, &[&["chart"], &BIRTH[..], &["--node", "true"]].concat()));
+    assert_eq!(doc["nodeModel"], "true");
+    let text = stdout(hd_with(&home, &[&BIRTH[..], &["--format", "text"]].concat()));
+    assert!(!text.starts_with('{'), "{text}");
+
+    // --config names another file
+    let other = home.join("other.json");
+    std::fs::write(&other, r#"{"format": "jsonl"}"#).unwrap();
+    let line = stdout(hd(&[&["--config", other.to_str().unwrap(), "chart"], &BIRTH[..]].concat()));
+    assert_eq!(line.lines().count(), 1);
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&line).unwrap()["nodeModel"], "true");
+}
+
+#[test]
+fn invalid_config_is_an_input_error() {
+    let dir = scratch("config-invalid");
+    let path = dir.join("config.json");
+    for config in [r#"{"colour": "never"}"#, r#"{"format": "yaml"}"#] {
+        std::fs::write(&path, config).unwrap();
+        let out = hd(&[&["--config", path.to_str().unwrap()], &BIRTH[..]].concat());
+        assert_eq!(out.status.code(), Some(2), "{config}");
+        assert!(String::from_utf8(out.stderr).unwrap().contains("invalid config"), "{config}");
+    }
+}
@@ -0,0 +1,413 @@
+use chrono::{DateTime, Datelike, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Mutex;
+use crate::error::{HdError, Result};
+use crate::gates::longitude_to_subdivision;
+use swiss_eph::safe::{self, Planet};
+
+/// Planetary activation in the chart
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Activation {
+    /// Body name ("Sun", "Earth", "North Node", ...)
+    pub planet: &'static str,
+    /// Tropical ecliptic longitude in degrees (0..360)
+    pub longitude: f64,
+    /// Gate number (1..=64)
+    pub gate: u8,
+    /// Line within the gate (1..=6)
+    pub line: u8,
+    /// Color within the line (1..=6)
+    pub color: u8,
+    /// Tone within the color (1..=6)
+    pub tone: u8,
+    /// Base within the tone (1..=5)
+    pub base: u8,
+    /// Fraction of the line already traversed (0.0..1.0)
+    pub line_position: f64,
+}
+
+/// Complete chart (personality + design)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Chart {
+    /// Activations at the birth moment (conscious)
+    pub personality: Vec<Activation>,
+    /// Activations 88° of solar arc before birth (unconscious)
+    pub design: Vec<Activation>,
+    /// Design moment in UTC, when the Sun was 88° behind its birth position
+    pub design_at: DateTime<Utc>,
+    /// Lunar node model used for the North and South Node
+    pub node_model: NodeModel,
+    /// Opt-in extra bodies at the birth moment; never part of definition
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub personality_extras: Vec<Activation>,
+    /// Opt-in extra bodies at the design moment; never part of definition
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub design_extras: Vec<Activation>,
+}
+
+impl Chart {
+    /// All gates activated by either personality or design
+    pub fn gates(&self) -> HashSet<u8> {
+        self.personality.iter()
+            .chain(self.design.iter())
+            .map(|a| a.gate)
+            .collect()
+    }
+}
+
+/// Lunar node calculation. Most HD software uses the true (osculating)
+/// node; some schools and sites use the mean node, which can differ by up to
+/// ~1.7° and so land in another line or gate.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeModel {
+    #[default]
+    True,
+    Mean,
+}
+
+impl NodeModel {
+    pub const ALL: [NodeModel; 2] = [NodeModel::True, NodeModel::Mean];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            NodeModel::True => "True Node",
+            NodeModel::Mean => "Mean Node",
+        }
+    }
+
+    fn planet(&self) -> Planet {
+        match self {
+            NodeModel::True => Planet::TrueNode,
+            NodeModel::Mean => Planet::MeanNode,
+        }
+    }
+}
+
+/// Calculation settings beyond the birth moment
+#[derive(Debug, Clone, Default)]
+pub struct ChartOptions {
+    pub node_model: NodeModel,
+    /// Extra bodies listed beside the 13 activations
+    pub bodies: Vec<ExtraBody>,
+}
+
+/// Optional bodies outside the 13 classic activations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ExtraBody {
+    Chiron,
+    /// Black Moon Lilith (mean lunar apogee)
+    Lilith,
+}
+
+impl ExtraBody {
+    pub const ALL: [ExtraBody; 2] = [ExtraBody::Chiron, ExtraBody::Lilith];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExtraBody::Chiron => "Chiron",
+            ExtraBody::Lilith => "Lilith",
+        }
+    }
+
+    /// Body of a name, case-insensitive ("chiron", "lilith")
+    pub fn from_name(name: &str) -> Option<ExtraBody> {
+        ExtraBody::ALL.into_iter().find(|b| b.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    fn planet(&self) -> Planet {
+        match self {
+            ExtraBody::Chiron => Planet::Chiron,
+            ExtraBody::Lilith => Planet::MeanApog,
+        }
+    }
+}
+
+/// Planet definitions for HD: (SwissEph planet, name, is_opposite).
+/// The nodes are listed as `TrueNode` and follow the [`NodeModel`].
+struct PlanetDef {
+    planet: Planet,
+    name: &'static str,
+    opposite: bool,
+}
+
+const HD_PLANETS: &[PlanetDef] = &[
+    PlanetDef { planet: Planet::Sun, name: "Sun", opposite: false },
+    PlanetDef { planet: Planet::Sun, name: "Earth", opposite: true },
+    PlanetDef { planet: Planet::Moon, name: "Moon", opposite: false },
+    PlanetDef { planet: Planet::TrueNode, name: "North Node", opposite: false },
+    PlanetDef { planet: Planet::TrueNode, name: "South Node", opposite: true },
+    PlanetDef { planet: Planet::Mercury, name: "Mercury", opposite: false },
+    PlanetDef { planet: Planet::Venus, name: "Venus", opposite: false },
+    PlanetDef { planet: Planet::Mars, name: "Mars", opposite: false },
+    PlanetDef { planet: Planet::Jupiter, name: "Jupiter", opposite: false },
+    PlanetDef { planet: Planet::Saturn, name: "Saturn", opposite: false },
+    PlanetDef { planet: Planet::Uranus, name: "Uranus", opposite: false },
+    PlanetDef { planet: Planet::Neptune, name: "Neptune", opposite: false },
+    PlanetDef { planet: Planet::Pluto, name: "Pluto", opposite: false },
+];
+
+/// Earliest supported birth year (Moshier ephemeris range)
+pub const MIN_YEAR: i32 = -2999;
+/// Latest supported birth year (Moshier ephemeris range)
+pub const MAX_YEAR: i32 = 2999;
+
+/// Default calculation flags (speed + ecliptic)
+const CALC_FLAGS: i32 = 0; // SEFLG_SPEED = 256, but 0 = default ecliptic
+/// Flags for positions with daily motion (SEFLG_SPEED)
+const SPEED_FLAGS: i32 = 256;
+
+/// Solar arc between the Design and the birth moment in degrees
+const DESIGN_ARC: f64 = 88.0;
+/// Design search bracket in days before birth. The Sun moves between
+/// ~0.953°/day (aphelion) and ~1.019°/day (perihelion), so 88° takes
+/// between ~86.4 and ~92.4 days.
+const DESIGN_BRACKET: (f64, f64) = (84.0, 95.0);
+/// Design search stops once the Sun is this close to its target (degrees,
+/// about a millisecond of solar motion)
+const DESIGN_TOLERANCE: f64 = 1e-8;
+const DESIGN_MAX_ITERATIONS: usize = 60;
+
+fn normalize(deg: f64) -> f64 {
+    let mut d = deg % 360.0;
+    if d < 0.0 { d += 360.0; }
+    d
+}
+
+/// Names of the bodies in activation order
+pub fn body_names() -> impl Iterator<Item = &'static str> {
+    HD_PLANETS.iter().map(|def| def.name)
+}
+
+/// Longitude of the body at `index` in [`body_names`] order
+pub(crate) fn body_longitude(index: usize, jd: f64, node: NodeModel) -> Result<f64> {
+    let def = &HD_PLANETS[index];
+    let planet = if matches!(def.planet, Planet::TrueNode) { node.planet() } else { def.planet };
+    let lon = calc(jd, planet, CALC_FLAGS)?.longitude;
+    Ok(if def.opposite { normalize(lon + 180.0) } else { lon })
+}
+
+/// Calculate all planetary positions for a given Julian Day
+fn calculate_positions(jd: f64, node: NodeModel) -> Result<Vec<Activation>> {
+    HD_PLANETS.iter().enumerate()
+        .map(|(index, def)| Ok(activation(def.name, body_longitude(index, jd, node)?)))
+        .collect()
+}
+
+/// Positions of the requested extra bodies for a given Julian Day
+fn calculate_extras(jd: f64, bodies: &[ExtraBody]) -> Result<Vec<Activation>> {
+    bodies.iter()
+        .map(|body| Ok(activation(body.name(), planet_longitude(body.planet(), jd)?)))
+        .collect()
+}
+
+fn activation(planet: &'static str, lon: f64) -> Activation {
+    let sub = longitude_to_subdivision(lon);
+    Activation {
+        planet,
+        longitude: lon,
+        gate: sub.gate,
+        line: sub.line,
+        color: sub.color,
+        tone: sub.tone,
+        base: sub.base,
+        line_position: sub.line_position,
+    }
+}
+
+/// Longitude of a Swiss Ephemeris body at a Julian Day
+pub(crate) fn planet_longitude(planet: Planet, jd: f64) -> Result<f64> {
+    Ok(calc(jd, planet, CALC_FLAGS)?.longitude)
+}
+
+/// Directory with Swiss Ephemeris data files (`sepl_*.se1`, `seas_*.se1`).
+///
+/// Without data files the built-in Moshier ephemeris is used, which covers
+/// the Sun, Moon, planets and nodes but not asteroids such as Chiron.
+pub fn set_ephemeris_path(path: &str) {
+    let _guard = EPHEMERIS.lock().unwrap_or_else(|e| e.into_inner());
+    safe::set_ephe_path(path);
+}
+
+/// Swiss Ephemeris keeps global state (open files, caches), so calls into
+/// it from several threads are serialized
+static EPHEMERIS: Mutex<()> = Mutex::new(());
+
+fn calc(jd: f64, planet: Planet, flags: i32) -> Result<safe::Position> {
+    let _guard = EPHEMERIS.lock().unwrap_or_else(|e| e.into_inner());
+    Ok(safe::calc_ut(jd, planet as i32, flags)?)
+}
+
+/// Find the Design date (when Sun was 88° behind birth Sun)
+pub(crate) fn find_design_jd(birth_jd: f64) -> Result<f64> {
+    let target = normalize(planet_longitude(Planet::Sun, birth_jd)? - DESIGN_ARC);
+    solve_sun(target, birth_jd - DESIGN_BRACKET.1, birth_jd - DESIGN_BRACKET.0)?
+        .ok_or_else(|| HdError::DesignNotConverged(instant_from_jd(birth_jd).to_rfc3339()))
+}
+
+/// Birth moment whose Design moment is `design_jd`, the inverse of
+/// [`find_design_jd`]
+pub(crate) fn birth_jd_for_design(design_jd: f64) -> Result<f64> {
+    let target = normalize(planet_longitude(Planet::Sun, design_jd)? + DESIGN_ARC);
+    solve_sun(target, design_jd + DESIGN_BRACKET.0, design_jd + DESIGN_BRACKET.1)?
+        .ok_or_else(|| HdError::DesignNotConverged(instant_from_jd(design_jd).to_rfc3339()))
+}
+
+/// Julian Day in `lo..=hi` at which the Sun reaches `target` longitude, or
+/// `None` if the bracket does not contain it or the search does not converge.
+///
+/// Newton steps use the Sun's actual speed and are kept inside the bracket;
+/// a step leaving the bracket bisects instead.
+fn solve_sun(target: f64, mut lo: f64, mut hi: f64) -> Result<Option<f64>> {
+    // Signed arc of the Sun past the target in (-180, 180], and its speed
+    let offset = |jd: f64| -> Result<(f64, f64)> {
+        let sun = calc(jd, Planet::Sun, SPEED_FLAGS)?;
+        let d = (sun.longitude - target).rem_euclid(360.0);
+        Ok((if d > 180.0 { d - 360.0 } else { d }, sun.longitude_speed))
+    };
+
+    let ((d_lo, _), (d_hi, speed_hi)) = (offset(lo)?, offset(hi)?);
+    if d_lo >= 0.0 || d_hi <= 0.0 {
+        return Ok(None);
+    }
+    let mut jd = hi - d_hi / speed_hi;
+    for _ in 0..DESIGN_MAX_ITERATIONS {
+        if !(lo..=hi).contains(&jd) {
+            jd = (lo + hi) / 2.0;
+        }
+        let (d, speed) = offset(jd)?;
+        if d.abs() < DESIGN_TOLERANCE {
+            return Ok(Some(jd));
+        }
+        if d < 0.0 { lo = jd } else { hi = jd }
+        jd = if speed > 0.0 { jd - d / speed } else { (lo + hi) / 2.0 };
+    }
+    Ok(None)
+}
+
+/// Calculate a complete Human Design chart.
+///
+/// `hour` is local decimal hours (14.5 = 14:30) and `tz_offset` the UTC offset
+/// in hours at the birth moment.
+pub fn calculate_chart(year: i32, month: u32, day: u32, hour: f64, tz_offset: f64) -> Result<Chart> {
+    calculate_chart_with(year, month, day, hour, tz_offset, &ChartOptions::default())
+}
+
+/// Calculate a chart with a node model and extra bodies.
+///
+/// Extra bodies appear in `personality_extras` and `design_extras` only, so
+/// type, authority and definition are the same as without them.
+pub fn calculate_chart_with(year: i32, month: u32, day: u32, hour: f64, tz_offset: f64, options: &ChartOptions) -> Result<Chart> {
+    validate_input(year, month, day, hour, tz_offset)?;
+    let utc_hour = hour - tz_offset;
+    let (y, m, d, h) = adjust_date(year, month, day, utc_hour);
+    chart_at_jd(safe::julday(y, m as i32, d as i32, h), options)
+}
+
+/// Calculate a complete chart for an instant, e.g. a planetary return
+pub fn calculate_chart_at(instant: DateTime<Utc>) -> Result<Chart> {
+    calculate_chart_at_with(instant, &ChartOptions::default())
+}
+
+/// Calculate a chart for an instant with a node model and extra bodies
+pub fn calculate_chart_at_with(instant: DateTime<Utc>, options: &ChartOptions) -> Result<Chart> {
+    chart_at_jd(julian_day(instant)?, options)
+}
+
+pub(crate) fn chart_at_jd(birth_jd: f64, options: &ChartOptions) -> Result<Chart> {
+    let design_jd = find_design_jd(birth_jd)?;
+    Ok(Chart {
+        personality: calculate_positions(birth_jd, options.node_model)?,
+        design: calculate_positions(design_jd, options.node_model)?,
+        design_at: instant_from_jd(design_jd),
+        node_model: options.node_model,
+        personality_extras: calculate_extras(birth_jd, &options.bodies)?,
+        design_extras: calculate_extras(design_jd, &options.bodies)?,
+    })
+}
+
+fn validate_input(year: i32, month: u32, day: u32, hour: f64, tz_offset: f64) -> Result<()> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(HdError::DateOutOfRange(year));
+    }
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(HdError::InvalidDate(format!("{:04}-{:02}-{:02}", year, month, day)));
+    }
+    if !(0.0..24.0).contains(&hour) {
+        return Err(HdError::InvalidTime(format!("{} h", hour)));
+    }
+    if !(-14.0..=14.0).contains(&tz_offset) {
+        return Err(HdError::InvalidTimezone(format!("{:+}", tz_offset)));
+    }
+    Ok(())
+}
+
+/// Julian Day (UT) of an instant
+pub fn julian_day(instant: DateTime<Utc>) -> Result<f64> {
+    if !(MIN_YEAR..=MAX_YEAR).contains(&instant.year()) {
+        return Err(HdError::DateOutOfRange(instant.year()));
+    }
+    let hour = instant.hour() as f64
+        + instant.minute() as f64 / 60.0
+        + (instant.second() as f64 + instant.nanosecond() as f64 / 1e9) / 3600.0;
+    Ok(safe::julday(instant.year(), instant.month() as i32, instant.day() as i32, hour))
+}
+
+/// Instant of a Julian Day (UT), to the millisecond
+pub fn instant_from_jd(jd: f64) -> DateTime<Utc> {
+    const UNIX_EPOCH_JD: f64 = 2_440_587.5;
+    let millis = ((jd - UNIX_EPOCH_JD) * 86_400_000.0).round() as i64;
+    DateTime::from_timestamp_millis(millis).unwrap_or_default()
+}
+
+/// Calculate transit positions at an instant
+pub fn calculate_transit_at(instant: DateTime<Utc>) -> Result<Vec<Activation>> {
+    calculate_transit_at_with(instant, NodeModel::True)
+}
+
+/// Calculate transit positions at an instant with the given node model
+pub fn calculate_transit_at_with(instant: DateTime<Utc>, node: NodeModel) -> Result<Vec<Activation>> {
+    calculate_positions(julian_day(instant)?, node)
+}
+
+/// Calculate current transit positions
+pub fn calculate_transit() -> Result<Vec<Activation>> {
+    calculate_transit_at(Utc::now())
+}
+
+fn adjust_date(year: i32, month: u32, day: u32, hour: f64) -> (i32, u32, u32, f64) {
+    if hour < 0.0 {
+        let (y, m, d) = prev_day(year, month, day);
+        (y, m, d, hour + 24.0)
+    } else if hour >= 24.0 {
+        let (y, m, d) = next_day(year, month, day);
+        (y, m, d, hour - 24.0)
+    } else {
+        (year, month, day, hour)
+    }
+}
+
+fn prev_day(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    if day > 1 { (year, month, day - 1) }
+    else if month > 1 { (year, month - 1, days_in_month(year, month - 1)) }
+    else { (year - 1, 12, 31) }
+}
+
+fn next_day(year: i32, month: u32, day: u32) -> (i32, u32, u32) {
+    let max = days_in_month(year, month);
+    if day < max { (year, month, day + 1) }
+    else if month < 12 { (year, month + 1, 1) }
+    else { (year + 1, 1, 1) }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 => if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) { 29 } else { 28 },
+        _ => 30,
+    }
+}
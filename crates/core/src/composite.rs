@@ -0,0 +1,114 @@
+//! Composite (connection) chart of two people.
+//!
+//! The two charts' gates are merged into one bodygraph. Every channel the
+//! merged gates define is classified by how the two people contribute to it:
+//!
+//! - electromagnetic: each person has one gate and neither has the channel
+//! - companionship: both people have the whole channel
+//! - dominance: one person has the whole channel, the other neither gate
+//! - compromise: one person has the whole channel, the other only one gate
+
+use serde::Serialize;
+use std::collections::HashSet;
+use crate::chart::Chart;
+use crate::types::{analyze_gates, DefinedChannel, GateAnalysis};
+
+/// One of the two people in a composite
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Person {
+    A,
+    B,
+}
+
+impl Person {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Person::A => "A",
+            Person::B => "B",
+        }
+    }
+}
+
+/// How the two people together define a channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum ConnectionKind {
+    Electromagnetic,
+    Companionship,
+    Dominance,
+    Compromise,
+}
+
+impl ConnectionKind {
+    pub const ALL: [ConnectionKind; 4] = [
+        ConnectionKind::Electromagnetic, ConnectionKind::Companionship,
+        ConnectionKind::Dominance, ConnectionKind::Compromise,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConnectionKind::Electromagnetic => "Electromagnetic",
+            ConnectionKind::Companionship => "Companionship",
+            ConnectionKind::Dominance => "Dominance",
+            ConnectionKind::Compromise => "Compromise",
+        }
+    }
+}
+
+/// A channel defined in the composite
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeChannel {
+    #[serde(flatten)]
+    pub channel: DefinedChannel,
+    pub kind: ConnectionKind,
+    /// Person holding the whole channel alone (dominance and compromise)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub holder: Option<Person>,
+}
+
+/// Composite of two charts
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Composite {
+    /// Channels defined by the merged gates, classified
+    pub channels: Vec<CompositeChannel>,
+    /// Type, authority and definition of the merged gates
+    pub combined: GateAnalysis,
+}
+
+impl Composite {
+    /// Number of channels of one kind
+    pub fn count(&self, kind: ConnectionKind) -> usize {
+        self.channels.iter().filter(|c| c.kind == kind).count()
+    }
+}
+
+/// Merge two charts and classify the channels they define together
+pub fn composite(a: &Chart, b: &Chart) -> Composite {
+    let gates_a = a.gates();
+    let gates_b = b.gates();
+    let merged: HashSet<u8> = gates_a.union(&gates_b).copied().collect();
+    let combined = analyze_gates(&merged);
+
+    let channels = combined.defined_channels.iter()
+        .map(|ch| {
+            let (kind, holder) = classify(ch, &gates_a, &gates_b);
+            CompositeChannel { channel: ch.clone(), kind, holder }
+        })
+        .collect();
+
+    Composite { channels, combined }
+}
+
+/// Classify a channel that the merged gates define
+fn classify(ch: &DefinedChannel, gates_a: &HashSet<u8>, gates_b: &HashSet<u8>) -> (ConnectionKind, Option<Person>) {
+    let has = |gates: &HashSet<u8>| (gates.contains(&ch.gate1) as u8) + (gates.contains(&ch.gate2) as u8);
+    match (has(gates_a), has(gates_b)) {
+        (2, 2) => (ConnectionKind::Companionship, None),
+        (2, 0) => (ConnectionKind::Dominance, Some(Person::A)),
+        (0, 2) => (ConnectionKind::Dominance, Some(Person::B)),
+        (2, _) => (ConnectionKind::Compromise, Some(Person::A)),
+        (_, 2) => (ConnectionKind::Compromise, Some(Person::B)),
+        _ => (ConnectionKind::Electromagnetic, None),
+    }
+}
@@ -0,0 +1,310 @@
+//! Incarnation Crosses.
+//!
+//! A cross is named by the Personality Sun gate and the angle, which follows
+//! from the profile: Right Angle (1/3 through 4/6), Juxtaposition (4/1) and
+//! Left Angle (5/1 through 6/3). 64 gates × 3 angles give the 192 crosses.
+
+use serde::Serialize;
+use crate::gates::GATE_ORDER;
+
+/// Angle of an Incarnation Cross
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Angle {
+    RightAngle,
+    Juxtaposition,
+    LeftAngle,
+}
+
+impl Angle {
+    /// Angle for a (personality, design) profile
+    pub fn from_profile(profile: (u8, u8)) -> Angle {
+        match profile {
+            (4, 1) => Angle::Juxtaposition,
+            (5, _) | (6, _) => Angle::LeftAngle,
+            _ => Angle::RightAngle,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Angle::RightAngle => "Right Angle",
+            Angle::Juxtaposition => "Juxtaposition",
+            Angle::LeftAngle => "Left Angle",
+        }
+    }
+}
+
+/// Quarter of the mandala holding the Personality Sun
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Quarter {
+    Initiation,
+    Civilization,
+    Duality,
+    Mutation,
+}
+
+impl Quarter {
+    /// Quarter containing a gate; the Quarter of Initiation begins at Gate 13
+    pub fn of_gate(gate: u8) -> Quarter {
+        let index = GATE_ORDER.iter().position(|&g| g == gate).unwrap_or(0);
+        match (index + 64 - 2) % 64 / 16 {
+            0 => Quarter::Initiation,
+            1 => Quarter::Civilization,
+            2 => Quarter::Duality,
+            _ => Quarter::Mutation,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Quarter::Initiation => "Initiation",
+            Quarter::Civilization => "Civilization",
+            Quarter::Duality => "Duality",
+            Quarter::Mutation => "Mutation",
+        }
+    }
+
+    /// Theme of the quarter, e.g. "Purpose fulfilled through Mind"
+    pub fn theme(&self) -> &'static str {
+        match self {
+            Quarter::Initiation => "Purpose fulfilled through Mind",
+            Quarter::Civilization => "Purpose fulfilled through Form",
+            Quarter::Duality => "Purpose fulfilled through Bonding",
+            Quarter::Mutation => "Purpose fulfilled through Transformation",
+        }
+    }
+}
+
+/// One entry of the cross table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cross {
+    pub angle: Angle,
+    /// Typical (p_sun, p_earth, d_sun, d_earth) gates
+    pub gates: (u8, u8, u8, u8),
+    pub name: &'static str,
+}
+
+/// Named Incarnation Cross of a chart
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IncarnationCross {
+    /// Full name, e.g. "Right Angle Cross of the Sphinx 4"
+    pub name: &'static str,
+    pub angle: Angle,
+    pub quarter: Quarter,
+    /// Actual (p_sun, p_earth, d_sun, d_earth) gates of the chart
+    pub gates: (u8, u8, u8, u8),
+}
+
+/// Look up the cross for a Personality Sun gate and angle
+pub fn find_cross(p_sun: u8, angle: Angle) -> Option<&'static Cross> {
+    CROSSES.iter().find(|c| c.angle == angle && c.gates.0 == p_sun)
+}
+
+/// Name a chart's cross from its four gates and profile
+pub fn incarnation_cross(gates: (u8, u8, u8, u8), profile: (u8, u8)) -> Option<IncarnationCross> {
+    let angle = Angle::from_profile(profile);
+    let cross = find_cross(gates.0, angle)?;
+    Some(IncarnationCross {
+        name: cross.name,
+        angle,
+        quarter: Quarter::of_gate(gates.0),
+        gates,
+    })
+}
+
+/// All 192 crosses, by angle, in mandala order from Gate 13
+pub const CROSSES: [Cross; 192] = [
+    Cross { angle: Angle::RightAngle, gates: (13, 7, 1, 2), name: "Right Angle Cross of the Sphinx 1" },
+    Cross { angle: Angle::RightAngle, gates: (49, 4, 43, 23), name: "Right Angle Cross of Explanation 1" },
+    Cross { angle: Angle::RightAngle, gates: (30, 29, 14, 8), name: "Right Angle Cross of Contagion 1" },
+    Cross { angle: Angle::RightAngle, gates: (55, 59, 34, 20), name: "Right Angle Cross of the Sleeping Phoenix 1" },
+    Cross { angle: Angle::RightAngle, gates: (37, 40, 9, 16), name: "Right Angle Cross of Planning 1" },
+    Cross { angle: Angle::RightAngle, gates: (63, 64, 5, 35), name: "Right Angle Cross of Consciousness 1" },
+    Cross { angle: Angle::RightAngle, gates: (22, 47, 26, 45), name: "Right Angle Cross of Rulership 1" },
+    Cross { angle: Angle::RightAngle, gates: (36, 6, 11, 12), name: "Right Angle Cross of Eden 1" },
+    Cross { angle: Angle::RightAngle, gates: (25, 46, 10, 15), name: "Right Angle Cross of the Vessel of Love 1" },
+    Cross { angle: Angle::RightAngle, gates: (17, 18, 58, 52), name: "Right Angle Cross of Service 1" },
+    Cross { angle: Angle::RightAngle, gates: (21, 48, 38, 39), name: "Right Angle Cross of Tension 1" },
+    Cross { angle: Angle::RightAngle, gates: (51, 57, 54, 53), name: "Right Angle Cross of Penetration 1" },
+    Cross { angle: Angle::RightAngle, gates: (42, 32, 61, 62), name: "Right Angle Cross of Maya 1" },
+    Cross { angle: Angle::RightAngle, gates: (3, 50, 60, 56), name: "Right Angle Cross of Laws 1" },
+    Cross { angle: Angle::RightAngle, gates: (27, 28, 41, 31), name: "Right Angle Cross of the Unexpected 1" },
+    Cross { angle: Angle::RightAngle, gates: (24, 44, 19, 33), name: "Right Angle Cross of the Four Ways 1" },
+    Cross { angle: Angle::RightAngle, gates: (2, 1, 13, 7), name: "Right Angle Cross of the Sphinx 2" },
+    Cross { angle: Angle::RightAngle, gates: (23, 43, 49, 4), name: "Right Angle Cross of Explanation 2" },
+    Cross { angle: Angle::RightAngle, gates: (8, 14, 30, 29), name: "Right Angle Cross of Contagion 2" },
+    Cross { angle: Angle::RightAngle, gates: (20, 34, 55, 59), name: "Right Angle Cross of the Sleeping Phoenix 2" },
+    Cross { angle: Angle::RightAngle, gates: (16, 9, 37, 40), name: "Right Angle Cross of Planning 2" },
+    Cross { angle: Angle::RightAngle, gates: (35, 5, 63, 64), name: "Right Angle Cross of Consciousness 2" },
+    Cross { angle: Angle::RightAngle, gates: (45, 26, 22, 47), name: "Right Angle Cross of Rulership 2" },
+    Cross { angle: Angle::RightAngle, gates: (12, 11, 36, 6), name: "Right Angle Cross of Eden 2" },
+    Cross { angle: Angle::RightAngle, gates: (15, 10, 25, 46), name: "Right Angle Cross of the Vessel of Love 2" },
+    Cross { angle: Angle::RightAngle, gates: (52, 58, 17, 18), name: "Right Angle Cross of Service 2" },
+    Cross { angle: Angle::RightAngle, gates: (39, 38, 21, 48), name: "Right Angle Cross of Tension 2" },
+    Cross { angle: Angle::RightAngle, gates: (53, 54, 51, 57), name: "Right Angle Cross of Penetration 2" },
+    Cross { angle: Angle::RightAngle, gates: (62, 61, 42, 32), name: "Right Angle Cross of Maya 2" },
+    Cross { angle: Angle::RightAngle, gates: (56, 60, 3, 50), name: "Right Angle Cross of Laws 2" },
+    Cross { angle: Angle::RightAngle, gates: (31, 41, 27, 28), name: "Right Angle Cross of the Unexpected 2" },
+    Cross { angle: Angle::RightAngle, gates: (33, 19, 24, 44), name: "Right Angle Cross of the Four Ways 2" },
+    Cross { angle: Angle::RightAngle, gates: (7, 13, 2, 1), name: "Right Angle Cross of the Sphinx 3" },
+    Cross { angle: Angle::RightAngle, gates: (4, 49, 23, 43), name: "Right Angle Cross of Explanation 3" },
+    Cross { angle: Angle::RightAngle, gates: (29, 30, 8, 14), name: "Right Angle Cross of Contagion 3" },
+    Cross { angle: Angle::RightAngle, gates: (59, 55, 20, 34), name: "Right Angle Cross of the Sleeping Phoenix 3" },
+    Cross { angle: Angle::RightAngle, gates: (40, 37, 16, 9), name: "Right Angle Cross of Planning 3" },
+    Cross { angle: Angle::RightAngle, gates: (64, 63, 35, 5), name: "Right Angle Cross of Consciousness 3" },
+    Cross { angle: Angle::RightAngle, gates: (47, 22, 45, 26), name: "Right Angle Cross of Rulership 3" },
+    Cross { angle: Angle::RightAngle, gates: (6, 36, 12, 11), name: "Right Angle Cross of Eden 3" },
+    Cross { angle: Angle::RightAngle, gates: (46, 25, 15, 10), name: "Right Angle Cross of the Vessel of Love 3" },
+    Cross { angle: Angle::RightAngle, gates: (18, 17, 52, 58), name: "Right Angle Cross of Service 3" },
+    Cross { angle: Angle::RightAngle, gates: (48, 21, 39, 38), name: "Right Angle Cross of Tension 3" },
+    Cross { angle: Angle::RightAngle, gates: (57, 51, 53, 54), name: "Right Angle Cross of Penetration 3" },
+    Cross { angle: Angle::RightAngle, gates: (32, 42, 62, 61), name: "Right Angle Cross of Maya 3" },
+    Cross { angle: Angle::RightAngle, gates: (50, 3, 56, 60), name: "Right Angle Cross of Laws 3" },
+    Cross { angle: Angle::RightAngle, gates: (28, 27, 31, 41), name: "Right Angle Cross of the Unexpected 3" },
+    Cross { angle: Angle::RightAngle, gates: (44, 24, 33, 19), name: "Right Angle Cross of the Four Ways 3" },
+    Cross { angle: Angle::RightAngle, gates: (1, 2, 7, 13), name: "Right Angle Cross of the Sphinx 4" },
+    Cross { angle: Angle::RightAngle, gates: (43, 23, 4, 49), name: "Right Angle Cross of Explanation 4" },
+    Cross { angle: Angle::RightAngle, gates: (14, 8, 29, 30), name: "Right Angle Cross of Contagion 4" },
+    Cross { angle: Angle::RightAngle, gates: (34, 20, 59, 55), name: "Right Angle Cross of the Sleeping Phoenix 4" },
+    Cross { angle: Angle::RightAngle, gates: (9, 16, 40, 37), name: "Right Angle Cross of Planning 4" },
+    Cross { angle: Angle::RightAngle, gates: (5, 35, 64, 63), name: "Right Angle Cross of Consciousness 4" },
+    Cross { angle: Angle::RightAngle, gates: (26, 45, 47, 22), name: "Right Angle Cross of Rulership 4" },
+    Cross { angle: Angle::RightAngle, gates: (11, 12, 6, 36), name: "Right Angle Cross of Eden 4" },
+    Cross { angle: Angle::RightAngle, gates: (10, 15, 46, 25), name: "Right Angle Cross of the Vessel of Love 4" },
+    Cross { angle: Angle::RightAngle, gates: (58, 52, 18, 17), name: "Right Angle Cross of Service 4" },
+    Cross { angle: Angle::RightAngle, gates: (38, 39, 48, 21), name: "Right Angle Cross of Tension 4" },
+    Cross { angle: Angle::RightAngle, gates: (54, 53, 57, 51), name: "Right Angle Cross of Penetration 4" },
+    Cross { angle: Angle::RightAngle, gates: (61, 62, 32, 42), name: "Right Angle Cross of Maya 4" },
+    Cross { angle: Angle::RightAngle, gates: (60, 56, 50, 3), name: "Right Angle Cross of Laws 4" },
+    Cross { angle: Angle::RightAngle, gates: (41, 31, 28, 27), name: "Right Angle Cross of the Unexpected 4" },
+    Cross { angle: Angle::RightAngle, gates: (19, 33, 44, 24), name: "Right Angle Cross of the Four Ways 4" },
+    Cross { angle: Angle::Juxtaposition, gates: (13, 7, 43, 23), name: "Juxtaposition Cross of Listening" },
+    Cross { angle: Angle::Juxtaposition, gates: (49, 4, 14, 8), name: "Juxtaposition Cross of Principles" },
+    Cross { angle: Angle::Juxtaposition, gates: (30, 29, 34, 20), name: "Juxtaposition Cross of Fates" },
+    Cross { angle: Angle::Juxtaposition, gates: (55, 59, 9, 16), name: "Juxtaposition Cross of Moods" },
+    Cross { angle: Angle::Juxtaposition, gates: (37, 40, 5, 35), name: "Juxtaposition Cross of Bargains" },
+    Cross { angle: Angle::Juxtaposition, gates: (63, 64, 26, 45), name: "Juxtaposition Cross of Doubts" },
+    Cross { angle: Angle::Juxtaposition, gates: (22, 47, 11, 12), name: "Juxtaposition Cross of Grace" },
+    Cross { angle: Angle::Juxtaposition, gates: (36, 6, 10, 15), name: "Juxtaposition Cross of Crisis" },
+    Cross { angle: Angle::Juxtaposition, gates: (25, 46, 58, 52), name: "Juxtaposition Cross of Innocence" },
+    Cross { angle: Angle::Juxtaposition, gates: (17, 18, 38, 39), name: "Juxtaposition Cross of Opinions" },
+    Cross { angle: Angle::Juxtaposition, gates: (21, 48, 54, 53), name: "Juxtaposition Cross of Control" },
+    Cross { angle: Angle::Juxtaposition, gates: (51, 57, 61, 62), name: "Juxtaposition Cross of Shock" },
+    Cross { angle: Angle::Juxtaposition, gates: (42, 32, 60, 56), name: "Juxtaposition Cross of Completion" },
+    Cross { angle: Angle::Juxtaposition, gates: (3, 50, 41, 31), name: "Juxtaposition Cross of Mutation" },
+    Cross { angle: Angle::Juxtaposition, gates: (27, 28, 19, 33), name: "Juxtaposition Cross of Caring" },
+    Cross { angle: Angle::Juxtaposition, gates: (24, 44, 13, 7), name: "Juxtaposition Cross of Rationalization" },
+    Cross { angle: Angle::Juxtaposition, gates: (2, 1, 49, 4), name: "Juxtaposition Cross of the Driver" },
+    Cross { angle: Angle::Juxtaposition, gates: (23, 43, 30, 29), name: "Juxtaposition Cross of Assimilation" },
+    Cross { angle: Angle::Juxtaposition, gates: (8, 14, 55, 59), name: "Juxtaposition Cross of Contribution" },
+    Cross { angle: Angle::Juxtaposition, gates: (20, 34, 37, 40), name: "Juxtaposition Cross of the Now" },
+    Cross { angle: Angle::Juxtaposition, gates: (16, 9, 63, 64), name: "Juxtaposition Cross of Experimentation" },
+    Cross { angle: Angle::Juxtaposition, gates: (35, 5, 22, 47), name: "Juxtaposition Cross of Experience" },
+    Cross { angle: Angle::Juxtaposition, gates: (45, 26, 36, 6), name: "Juxtaposition Cross of Possession" },
+    Cross { angle: Angle::Juxtaposition, gates: (12, 11, 25, 46), name: "Juxtaposition Cross of Articulation" },
+    Cross { angle: Angle::Juxtaposition, gates: (15, 10, 17, 18), name: "Juxtaposition Cross of Extremes" },
+    Cross { angle: Angle::Juxtaposition, gates: (52, 58, 21, 48), name: "Juxtaposition Cross of Stillness" },
+    Cross { angle: Angle::Juxtaposition, gates: (39, 38, 51, 57), name: "Juxtaposition Cross of Provocation" },
+    Cross { angle: Angle::Juxtaposition, gates: (53, 54, 42, 32), name: "Juxtaposition Cross of Beginnings" },
+    Cross { angle: Angle::Juxtaposition, gates: (62, 61, 3, 50), name: "Juxtaposition Cross of Details" },
+    Cross { angle: Angle::Juxtaposition, gates: (56, 60, 27, 28), name: "Juxtaposition Cross of Stimulation" },
+    Cross { angle: Angle::Juxtaposition, gates: (31, 41, 24, 44), name: "Juxtaposition Cross of Influence" },
+    Cross { angle: Angle::Juxtaposition, gates: (33, 19, 2, 1), name: "Juxtaposition Cross of Retreat" },
+    Cross { angle: Angle::Juxtaposition, gates: (7, 13, 23, 43), name: "Juxtaposition Cross of Interaction" },
+    Cross { angle: Angle::Juxtaposition, gates: (4, 49, 8, 14), name: "Juxtaposition Cross of Formulization" },
+    Cross { angle: Angle::Juxtaposition, gates: (29, 30, 20, 34), name: "Juxtaposition Cross of Commitment" },
+    Cross { angle: Angle::Juxtaposition, gates: (59, 55, 16, 9), name: "Juxtaposition Cross of Strategy" },
+    Cross { angle: Angle::Juxtaposition, gates: (40, 37, 35, 5), name: "Juxtaposition Cross of Denial" },
+    Cross { angle: Angle::Juxtaposition, gates: (64, 63, 45, 26), name: "Juxtaposition Cross of Confusion" },
+    Cross { angle: Angle::Juxtaposition, gates: (47, 22, 12, 11), name: "Juxtaposition Cross of Oppression" },
+    Cross { angle: Angle::Juxtaposition, gates: (6, 36, 15, 10), name: "Juxtaposition Cross of Conflict" },
+    Cross { angle: Angle::Juxtaposition, gates: (46, 25, 52, 58), name: "Juxtaposition Cross of Serendipity" },
+    Cross { angle: Angle::Juxtaposition, gates: (18, 17, 39, 38), name: "Juxtaposition Cross of Correction" },
+    Cross { angle: Angle::Juxtaposition, gates: (48, 21, 53, 54), name: "Juxtaposition Cross of Depth" },
+    Cross { angle: Angle::Juxtaposition, gates: (57, 51, 62, 61), name: "Juxtaposition Cross of Intuition" },
+    Cross { angle: Angle::Juxtaposition, gates: (32, 42, 56, 60), name: "Juxtaposition Cross of Conservation" },
+    Cross { angle: Angle::Juxtaposition, gates: (50, 3, 31, 41), name: "Juxtaposition Cross of Values" },
+    Cross { angle: Angle::Juxtaposition, gates: (28, 27, 33, 19), name: "Juxtaposition Cross of Risks" },
+    Cross { angle: Angle::Juxtaposition, gates: (44, 24, 7, 13), name: "Juxtaposition Cross of Alertness" },
+    Cross { angle: Angle::Juxtaposition, gates: (1, 2, 4, 49), name: "Juxtaposition Cross of Self-Expression" },
+    Cross { angle: Angle::Juxtaposition, gates: (43, 23, 29, 30), name: "Juxtaposition Cross of Insight" },
+    Cross { angle: Angle::Juxtaposition, gates: (14, 8, 59, 55), name: "Juxtaposition Cross of Empowering" },
+    Cross { angle: Angle::Juxtaposition, gates: (34, 20, 40, 37), name: "Juxtaposition Cross of Power" },
+    Cross { angle: Angle::Juxtaposition, gates: (9, 16, 64, 63), name: "Juxtaposition Cross of Focus" },
+    Cross { angle: Angle::Juxtaposition, gates: (5, 35, 47, 22), name: "Juxtaposition Cross of Habits" },
+    Cross { angle: Angle::Juxtaposition, gates: (26, 45, 6, 36), name: "Juxtaposition Cross of the Trickster" },
+    Cross { angle: Angle::Juxtaposition, gates: (11, 12, 46, 25), name: "Juxtaposition Cross of Ideas" },
+    Cross { angle: Angle::Juxtaposition, gates: (10, 15, 18, 17), name: "Juxtaposition Cross of Behavior" },
+    Cross { angle: Angle::Juxtaposition, gates: (58, 52, 48, 21), name: "Juxtaposition Cross of Vitality" },
+    Cross { angle: Angle::Juxtaposition, gates: (38, 39, 57, 51), name: "Juxtaposition Cross of Opposition" },
+    Cross { angle: Angle::Juxtaposition, gates: (54, 53, 32, 42), name: "Juxtaposition Cross of Ambition" },
+    Cross { angle: Angle::Juxtaposition, gates: (61, 62, 50, 3), name: "Juxtaposition Cross of Thinking" },
+    Cross { angle: Angle::Juxtaposition, gates: (60, 56, 28, 27), name: "Juxtaposition Cross of Limitation" },
+    Cross { angle: Angle::Juxtaposition, gates: (41, 31, 44, 24), name: "Juxtaposition Cross of Fantasy" },
+    Cross { angle: Angle::Juxtaposition, gates: (19, 33, 1, 2), name: "Juxtaposition Cross of Need" },
+    Cross { angle: Angle::LeftAngle, gates: (13, 7, 43, 23), name: "Left Angle Cross of Masks 1" },
+    Cross { angle: Angle::LeftAngle, gates: (49, 4, 14, 8), name: "Left Angle Cross of Revolution 1" },
+    Cross { angle: Angle::LeftAngle, gates: (30, 29, 34, 20), name: "Left Angle Cross of Industry 1" },
+    Cross { angle: Angle::LeftAngle, gates: (55, 59, 9, 16), name: "Left Angle Cross of Spirit 1" },
+    Cross { angle: Angle::LeftAngle, gates: (37, 40, 5, 35), name: "Left Angle Cross of Migration 1" },
+    Cross { angle: Angle::LeftAngle, gates: (63, 64, 26, 45), name: "Left Angle Cross of Dominion 1" },
+    Cross { angle: Angle::LeftAngle, gates: (22, 47, 11, 12), name: "Left Angle Cross of Informing 1" },
+    Cross { angle: Angle::LeftAngle, gates: (36, 6, 10, 15), name: "Left Angle Cross of the Plane 1" },
+    Cross { angle: Angle::LeftAngle, gates: (25, 46, 58, 52), name: "Left Angle Cross of Healing 1" },
+    Cross { angle: Angle::LeftAngle, gates: (17, 18, 38, 39), name: "Left Angle Cross of Upheaval 1" },
+    Cross { angle: Angle::LeftAngle, gates: (21, 48, 54, 53), name: "Left Angle Cross of Endeavor 1" },
+    Cross { angle: Angle::LeftAngle, gates: (51, 57, 61, 62), name: "Left Angle Cross of the Clarion 1" },
+    Cross { angle: Angle::LeftAngle, gates: (42, 32, 60, 56), name: "Left Angle Cross of Limitation 1" },
+    Cross { angle: Angle::LeftAngle, gates: (3, 50, 41, 31), name: "Left Angle Cross of Wishes 1" },
+    Cross { angle: Angle::LeftAngle, gates: (27, 28, 19, 33), name: "Left Angle Cross of Alignment 1" },
+    Cross { angle: Angle::LeftAngle, gates: (24, 44, 13, 7), name: "Left Angle Cross of Incarnation 1" },
+    Cross { angle: Angle::LeftAngle, gates: (2, 1, 49, 4), name: "Left Angle Cross of Defiance 1" },
+    Cross { angle: Angle::LeftAngle, gates: (23, 43, 30, 29), name: "Left Angle Cross of Dedication 1" },
+    Cross { angle: Angle::LeftAngle, gates: (8, 14, 55, 59), name: "Left Angle Cross of Uncertainty 1" },
+    Cross { angle: Angle::LeftAngle, gates: (20, 34, 37, 40), name: "Left Angle Cross of Duality 1" },
+    Cross { angle: Angle::LeftAngle, gates: (16, 9, 63, 64), name: "Left Angle Cross of Identification 1" },
+    Cross { angle: Angle::LeftAngle, gates: (35, 5, 22, 47), name: "Left Angle Cross of Separation 1" },
+    Cross { angle: Angle::LeftAngle, gates: (45, 26, 36, 6), name: "Left Angle Cross of Confrontation 1" },
+    Cross { angle: Angle::LeftAngle, gates: (12, 11, 25, 46), name: "Left Angle Cross of Education 1" },
+    Cross { angle: Angle::LeftAngle, gates: (15, 10, 17, 18), name: "Left Angle Cross of Prevention 1" },
+    Cross { angle: Angle::LeftAngle, gates: (52, 58, 21, 48), name: "Left Angle Cross of Demands 1" },
+    Cross { angle: Angle::LeftAngle, gates: (39, 38, 51, 57), name: "Left Angle Cross of Individualism 1" },
+    Cross { angle: Angle::LeftAngle, gates: (53, 54, 42, 32), name: "Left Angle Cross of Cycles 1" },
+    Cross { angle: Angle::LeftAngle, gates: (62, 61, 3, 50), name: "Left Angle Cross of Obscuration 1" },
+    Cross { angle: Angle::LeftAngle, gates: (56, 60, 27, 28), name: "Left Angle Cross of Distraction 1" },
+    Cross { angle: Angle::LeftAngle, gates: (31, 41, 24, 44), name: "Left Angle Cross of the Alpha 1" },
+    Cross { angle: Angle::LeftAngle, gates: (33, 19, 2, 1), name: "Left Angle Cross of Refinement 1" },
+    Cross { angle: Angle::LeftAngle, gates: (7, 13, 23, 43), name: "Left Angle Cross of Masks 2" },
+    Cross { angle: Angle::LeftAngle, gates: (4, 49, 8, 14), name: "Left Angle Cross of Revolution 2" },
+    Cross { angle: Angle::LeftAngle, gates: (29, 30, 20, 34), name: "Left Angle Cross of Industry 2" },
+    Cross { angle: Angle::LeftAngle, gates: (59, 55, 16, 9), name: "Left Angle Cross of Spirit 2" },
+    Cross { angle: Angle::LeftAngle, gates: (40, 37, 35, 5), name: "Left Angle Cross of Migration 2" },
+    Cross { angle: Angle::LeftAngle, gates: (64, 63, 45, 26), name: "Left Angle Cross of Dominion 2" },
+    Cross { angle: Angle::LeftAngle, gates: (47, 22, 12, 11), name: "Left Angle Cross of Informing 2" },
+    Cross { angle: Angle::LeftAngle, gates: (6, 36, 15, 10), name: "Left Angle Cross of the Plane 2" },
+    Cross { angle: Angle::LeftAngle, gates: (46, 25, 52, 58), name: "Left Angle Cross of Healing 2" },
+    Cross { angle: Angle::LeftAngle, gates: (18, 17, 39, 38), name: "Left Angle Cross of Upheaval 2" },
+    Cross { angle: Angle::LeftAngle, gates: (48, 21, 53, 54), name: "Left Angle Cross of Endeavor 2" },
+    Cross { angle: Angle::LeftAngle, gates: (57, 51, 62, 61), name: "Left Angle Cross of the Clarion 2" },
+    Cross { angle: Angle::LeftAngle, gates: (32, 42, 56, 60), name: "Left Angle Cross of Limitation 2" },
+    Cross { angle: Angle::LeftAngle, gates: (50, 3, 31, 41), name: "Left Angle Cross of Wishes 2" },
+    Cross { angle: Angle::LeftAngle, gates: (28, 27, 33, 19), name: "Left Angle Cross of Alignment 2" },
+    Cross { angle: Angle::LeftAngle, gates: (44, 24, 7, 13), name: "Left Angle Cross of Incarnation 2" },
+    Cross { angle: Angle::LeftAngle, gates: (1, 2, 4, 49), name: "Left Angle Cross of Defiance 2" },
+    Cross { angle: Angle::LeftAngle, gates: (43, 23, 29, 30), name: "Left Angle Cross of Dedication 2" },
+    Cross { angle: Angle::LeftAngle, gates: (14, 8, 59, 55), name: "Left Angle Cross of Uncertainty 2" },
+    Cross { angle: Angle::LeftAngle, gates: (34, 20, 40, 37), name: "Left Angle Cross of Duality 2" },
+    Cross { angle: Angle::LeftAngle, gates: (9, 16, 64, 63), name: "Left Angle Cross of Identification 2" },
+    Cross { angle: Angle::LeftAngle, gates: (5, 35, 47, 22), name: "Left Angle Cross of Separation 2" },
+    Cross { angle: Angle::LeftAngle, gates: (26, 45, 6, 36), name: "Left Angle Cross of Confrontation 2" },
+    Cross { angle: Angle::LeftAngle, gates: (11, 12, 46, 25), name: "Left Angle Cross of Education 2" },
+    Cross { angle: Angle::LeftAngle, gates: (10, 15, 18, 17), name: "Left Angle Cross of Prevention 2" },
+    Cross { angle: Angle::LeftAngle, gates: (58, 52, 48, 21), name: "Left Angle Cross of Demands 2" },
+    Cross { angle: Angle::LeftAngle, gates: (38, 39, 57, 51), name: "Left Angle Cross of Individualism 2" },
+    Cross { angle: Angle::LeftAngle, gates: (54, 53, 32, 42), name: "Left Angle Cross of Cycles 2" },
+    Cross { angle: Angle::LeftAngle, gates: (61, 62, 50, 3), name: "Left Angle Cross of Obscuration 2" },
+    Cross { angle: Angle::LeftAngle, gates: (60, 56, 28, 27), name: "Left Angle Cross of Distraction 2" },
+    Cross { angle: Angle::LeftAngle, gates: (41, 31, 44, 24), name: "Left Angle Cross of the Alpha 2" },
+    Cross { angle: Angle::LeftAngle, gates: (19, 33, 1, 2), name: "Left Angle Cross of Refinement 2" },
+];
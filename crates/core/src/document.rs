@@ -0,0 +1,231 @@
+//! Versioned, machine-readable chart document.
+//!
+//! This is the JSON shape emitted by `hd --format json`. Field names are
+//! camelCase so the web app can consume `chart` and `analysis` directly.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::BTreeSet;
+use crate::chart::{Chart, NodeModel};
+use crate::composite::Composite;
+use crate::geo::Place;
+use crate::ingress::Ingress;
+use crate::penta::Penta;
+use crate::rectify::Rectification;
+use crate::returns::PlanetReturn;
+use crate::search::{ActivationConstraint, SearchMatch};
+use crate::transit::{TransitOverlay, TransitWindow};
+use crate::types::ChartAnalysis;
+
+/// Schema version of [`ChartDocument`]; bumped on breaking changes
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Birth data as supplied by the user
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BirthInfo {
+    pub date: String,
+    pub time: String,
+    pub tz: String,
+    /// UTC offset in hours actually applied to the birth time
+    pub utc_offset: f64,
+    pub location: String,
+    /// Gazetteer entry the location resolved to, if any
+    pub place: Option<Place>,
+}
+
+/// Analysis plus the gate sets the web app keeps on its `ChartAnalysis`
+#[derive(Debug, Serialize)]
+pub struct AnalysisOutput<'a> {
+    #[serde(flatten)]
+    pub analysis: &'a ChartAnalysis,
+    #[serde(flatten)]
+    pub gates: GateSets,
+}
+
+/// Gate sets derived from the chart
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateSets {
+    pub personality_gates: BTreeSet<u8>,
+    pub design_gates: BTreeSet<u8>,
+    pub all_gates: BTreeSet<u8>,
+}
+
+impl GateSets {
+    pub fn from_chart(chart: &Chart) -> Self {
+        let personality_gates: BTreeSet<u8> = chart.personality.iter().map(|a| a.gate).collect();
+        let design_gates: BTreeSet<u8> = chart.design.iter().map(|a| a.gate).collect();
+        let all_gates = personality_gates.union(&design_gates).copied().collect();
+        GateSets { personality_gates, design_gates, all_gates }
+    }
+}
+
+/// Complete output document for one chart
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub birth: BirthInfo,
+    pub chart: &'a Chart,
+    pub analysis: AnalysisOutput<'a>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transit: Option<&'a TransitOverlay>,
+}
+
+impl<'a> ChartDocument<'a> {
+    pub fn new(birth: BirthInfo, chart: &'a Chart, analysis: &'a ChartAnalysis) -> Self {
+        ChartDocument {
+            version: SCHEMA_VERSION,
+            node_model: chart.node_model,
+            birth,
+            chart,
+            analysis: AnalysisOutput { analysis, gates: GateSets::from_chart(chart) },
+            transit: None,
+        }
+    }
+
+    pub fn with_transit(mut self, overlay: &'a TransitOverlay) -> Self {
+        self.transit = Some(overlay);
+        self
+    }
+}
+
+/// Output document for a composite of two people
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompositeDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub a: BirthInfo,
+    pub b: BirthInfo,
+    pub composite: &'a Composite,
+}
+
+impl<'a> CompositeDocument<'a> {
+    pub fn new(a: BirthInfo, b: BirthInfo, composite: &'a Composite, node_model: NodeModel) -> Self {
+        CompositeDocument { version: SCHEMA_VERSION, node_model, a, b, composite }
+    }
+}
+
+/// Output document for a Penta; member indices refer to `members`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PentaDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub members: Vec<BirthInfo>,
+    pub penta: &'a Penta,
+}
+
+impl<'a> PentaDocument<'a> {
+    pub fn new(members: Vec<BirthInfo>, penta: &'a Penta, node_model: NodeModel) -> Self {
+        PentaDocument { version: SCHEMA_VERSION, node_model, members, penta }
+    }
+}
+
+/// Output document for the transit positions at one instant
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    #[serde(flatten)]
+    pub transit: &'a TransitOverlay,
+}
+
+impl<'a> TransitDocument<'a> {
+    pub fn new(transit: &'a TransitOverlay, node_model: NodeModel) -> Self {
+        TransitDocument { version: SCHEMA_VERSION, node_model, transit }
+    }
+}
+
+/// Output document for a transit ingress timeline
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub ingresses: &'a [Ingress],
+}
+
+impl<'a> TimelineDocument<'a> {
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>, ingresses: &'a [Ingress], node_model: NodeModel) -> Self {
+        TimelineDocument { version: SCHEMA_VERSION, node_model, from, to, ingresses }
+    }
+}
+
+/// Output document for a personal transit forecast
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForecastDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub birth: BirthInfo,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub windows: &'a [TransitWindow],
+}
+
+impl<'a> ForecastDocument<'a> {
+    pub fn new(birth: BirthInfo, from: DateTime<Utc>, to: DateTime<Utc>, windows: &'a [TransitWindow], node_model: NodeModel) -> Self {
+        ForecastDocument { version: SCHEMA_VERSION, node_model, birth, from, to, windows }
+    }
+}
+
+/// Output document for planetary returns of one natal chart
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReturnDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub birth: BirthInfo,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub returns: &'a [PlanetReturn],
+}
+
+impl<'a> ReturnDocument<'a> {
+    pub fn new(birth: BirthInfo, from: DateTime<Utc>, to: DateTime<Utc>, returns: &'a [PlanetReturn], node_model: NodeModel) -> Self {
+        ReturnDocument { version: SCHEMA_VERSION, node_model, birth, from, to, returns }
+    }
+}
+
+/// Output document for a birth-time rectification; `birth` holds the
+/// start of the window
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RectifyDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub birth: BirthInfo,
+    #[serde(flatten)]
+    pub rectification: &'a Rectification,
+}
+
+impl<'a> RectifyDocument<'a> {
+    pub fn new(birth: BirthInfo, rectification: &'a Rectification, node_model: NodeModel) -> Self {
+        RectifyDocument { version: SCHEMA_VERSION, node_model, birth, rectification }
+    }
+}
+
+/// Output document for a reverse search
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchDocument<'a> {
+    pub version: u32,
+    pub node_model: NodeModel,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub activations: &'a [ActivationConstraint],
+    pub matches: &'a [SearchMatch],
+}
+
+impl<'a> SearchDocument<'a> {
+    pub fn new(from: DateTime<Utc>, to: DateTime<Utc>, activations: &'a [ActivationConstraint], matches: &'a [SearchMatch], node_model: NodeModel) -> Self {
+        SearchDocument { version: SCHEMA_VERSION, node_model, from, to, activations, matches }
+    }
+}
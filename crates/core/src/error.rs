@@ -0,0 +1,80 @@
+use std::fmt;
+use swiss_eph::safe::SwissEphError;
+
+/// Errors produced while parsing birth data or calculating a chart
+#[derive(Debug, Clone, PartialEq)]
+pub enum HdError {
+    /// Date could not be parsed or does not exist in the calendar
+    InvalidDate(String),
+    /// Time could not be parsed or is outside 00:00..24:00
+    InvalidTime(String),
+    /// Instant is neither an absolute date/time, `now`, nor a relative offset
+    InvalidInstant(String),
+    /// Time range is empty or reversed
+    InvalidRange(String),
+    /// Timezone is neither a UTC offset within UTC-14..UTC+14 nor an IANA zone name
+    InvalidTimezone(String),
+    /// Local time occurs twice because clocks were set back (DST end)
+    AmbiguousLocalTime(String),
+    /// Local time never occurred because clocks were set forward (DST start)
+    NonexistentLocalTime(String),
+    /// Location not found in the gazetteer
+    UnknownLocation(String),
+    /// Several places match the location equally well
+    AmbiguousLocation { query: String, candidates: Vec<String> },
+    /// Gazetteer data could not be loaded
+    Gazetteer(String),
+    /// Year outside the range covered by the ephemeris
+    DateOutOfRange(i32),
+    /// Swiss Ephemeris reported a failure
+    Ephemeris(String),
+    /// The Design moment could not be solved for the given birth moment
+    DesignNotConverged(String),
+    /// A required activation (e.g. Sun or Earth) is missing from the chart
+    MissingActivation(&'static str),
+    /// Number of people outside what a group analysis supports
+    GroupSize(usize),
+    /// Search constraint could not be parsed
+    InvalidQuery(String),
+}
+
+pub type Result<T> = std::result::Result<T, HdError>;
+
+impl fmt::Display for HdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HdError::InvalidDate(s) => write!(f, "invalid date '{}' (expected YYYY-MM-DD)", s),
+            HdError::InvalidTime(s) => write!(f, "invalid time '{}' (expected HH:MM)", s),
+            HdError::InvalidInstant(s) => write!(f, "invalid instant '{}' (expected e.g. 2026-12-21T10:00Z, now or +7d)", s),
+            HdError::InvalidRange(s) => write!(f, "invalid range {} (end must be after start)", s),
+            HdError::InvalidTimezone(s) => write!(f, "invalid timezone '{}' (expected UTC offset like +2 or IANA name like Europe/Helsinki)", s),
+            HdError::AmbiguousLocalTime(s) => write!(f, "ambiguous local time {} (clocks were set back)", s),
+            HdError::NonexistentLocalTime(s) => write!(f, "non-existent local time {} (clocks were set forward)", s),
+            HdError::UnknownLocation(s) => write!(f, "unknown location '{}'", s),
+            HdError::AmbiguousLocation { query, candidates } => {
+                write!(f, "ambiguous location '{}', matches:", query)?;
+                for c in candidates {
+                    write!(f, "\n  {}", c)?;
+                }
+                Ok(())
+            }
+            HdError::Gazetteer(msg) => write!(f, "cannot load gazetteer: {}", msg),
+            HdError::DateOutOfRange(year) => write!(f, "year {} is outside the supported range {}..={}",
+                year, crate::chart::MIN_YEAR, crate::chart::MAX_YEAR),
+            HdError::Ephemeris(msg) => write!(f, "ephemeris calculation failed: {}", msg),
+            HdError::DesignNotConverged(birth) => write!(f, "could not find the Design moment 88° before birth {}", birth),
+            HdError::MissingActivation(planet) => write!(f, "chart has no {} activation", planet),
+            HdError::GroupSize(n) => write!(f, "a Penta needs {} to {} people, got {}",
+                crate::penta::MIN_MEMBERS, crate::penta::MAX_MEMBERS, n),
+            HdError::InvalidQuery(s) => write!(f, "invalid search constraint '{}'", s),
+        }
+    }
+}
+
+impl std::error::Error for HdError {}
+
+impl From<SwissEphError> for HdError {
+    fn from(e: SwissEphError) -> Self {
+        HdError::Ephemeris(e.to_string())
+    }
+}
@@ -0,0 +1,290 @@
+//! Human Design gate order around the Rave Mandala.
+//! Gate 41 line 1 starts at 2°00' Aquarius (302° tropical).
+//! Each gate spans 5.625° (360/64), each line spans 0.9375° (5.625/6).
+//! Lines divide further into 6 colors, each color into 6 tones and each
+//! tone into 5 bases.
+
+use serde::Serialize;
+
+pub const HD_START_DEGREE: f64 = 302.0;
+pub const GATE_SIZE: f64 = 360.0 / 64.0; // 5.625°
+pub const LINE_SIZE: f64 = GATE_SIZE / 6.0; // 0.9375°
+pub const COLOR_SIZE: f64 = LINE_SIZE / 6.0; // 0.15625°
+pub const TONE_SIZE: f64 = COLOR_SIZE / 6.0; // ~0.026042°
+pub const BASE_SIZE: f64 = TONE_SIZE / 5.0; // ~0.005208°
+
+/// Bases around the whole mandala (64 × 6 × 6 × 6 × 5)
+const BASES_PER_CIRCLE: u32 = 69_120;
+
+/// Gate order around the mandala starting from Gate 41 at 302° tropical
+pub const GATE_ORDER: [u8; 64] = [
+    41, 19, 13, 49, 30, 55, 37, 63, 22, 36, 25, 17, 21, 51, 42, 3,
+    27, 24, 2, 23, 8, 20, 16, 35, 45, 12, 15, 52, 39, 53, 62, 56,
+    31, 33, 7, 4, 29, 59, 40, 64, 47, 6, 46, 18, 48, 57, 32, 50,
+    28, 44, 1, 43, 14, 34, 9, 5, 26, 11, 10, 58, 38, 54, 61, 60,
+];
+
+/// Gate keynotes, indexed by gate number - 1
+const GATE_NAMES: [&str; 64] = [
+    "Self-Expression", "Direction of the Self", "Ordering", "Formulization",
+    "Fixed Rhythms", "Friction", "The Role of the Self", "Contribution",
+    "Focus", "Behavior of the Self", "Ideas", "Caution",
+    "The Listener", "Power Skills", "Extremes", "Skills",
+    "Opinions", "Correction", "Wanting", "The Now",
+    "The Hunter/Huntress", "Openness", "Assimilation", "Rationalization",
+    "The Spirit of the Self", "The Egoist", "Caring", "The Game Player",
+    "Perseverance", "Recognition of Feelings", "Influence", "Continuity",
+    "Privacy", "Power", "Change", "Crisis",
+    "Friendship", "The Fighter", "Provocation", "Aloneness",
+    "Contraction", "Growth", "Insight", "Alertness",
+    "The Gatherer", "Determination of the Self", "Realization", "Depth",
+    "Principles", "Values", "Shock", "Stillness",
+    "Beginnings", "Ambition", "Spirit", "Stimulation",
+    "Intuitive Clarity", "Vitality", "Sexuality", "Acceptance",
+    "Mystery", "Details", "Doubt", "Confusion",
+];
+
+/// Keynote name of a gate, e.g. "Self-Expression" for Gate 1
+pub fn gate_name(gate: u8) -> &'static str {
+    match gate {
+        1..=64 => GATE_NAMES[gate as usize - 1],
+        _ => "",
+    }
+}
+
+/// Position of a longitude on the mandala down to the base
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Subdivision {
+    pub gate: u8,
+    /// 1..=6
+    pub line: u8,
+    /// 1..=6
+    pub color: u8,
+    /// 1..=6
+    pub tone: u8,
+    /// 1..=5
+    pub base: u8,
+    /// Fraction of the line already traversed (0.0..1.0)
+    pub line_position: f64,
+}
+
+/// Offset of a longitude from Gate 41 in base units, and the base index
+fn base_units(longitude: f64) -> (f64, u32) {
+    let offset = (longitude - HD_START_DEGREE).rem_euclid(360.0);
+    // rem_euclid can round tiny negative offsets up to exactly 360
+    let offset = if offset >= 360.0 { 0.0 } else { offset };
+    // Scale to base units; 69120 / 360 = 192 keeps the multiplication exact
+    let units = offset * (BASES_PER_CIRCLE as f64 / 360.0);
+    (units, (units as u32).min(BASES_PER_CIRCLE - 1))
+}
+
+/// Lines around the mandala (64 × 6)
+pub const LINES_PER_CIRCLE: u16 = 384;
+
+/// Index of the line around the mandala, counted from Gate 41 line 1 (0..384)
+pub fn longitude_to_line_index(longitude: f64) -> u16 {
+    (base_units(longitude).1 / 180) as u16
+}
+
+/// Convert ecliptic longitude to gate, line, color, tone and base
+pub fn longitude_to_subdivision(longitude: f64) -> Subdivision {
+    let (units, index) = base_units(longitude);
+    Subdivision {
+        gate: GATE_ORDER[(index / 1080) as usize],
+        line: (index / 180 % 6) as u8 + 1,
+        color: (index / 30 % 6) as u8 + 1,
+        tone: (index / 5 % 6) as u8 + 1,
+        base: (index % 5) as u8 + 1,
+        line_position: (units / 180.0).fract(),
+    }
+}
+
+/// Convert ecliptic longitude to HD gate and line
+pub fn longitude_to_gate_line(longitude: f64) -> (u8, u8) {
+    let s = longitude_to_subdivision(longitude);
+    (s.gate, s.line)
+}
+
+/// Centers in the bodygraph
+///
+/// Variants are ordered top to bottom as drawn on the bodygraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum Center {
+    Head,
+    Ajna,
+    Throat,
+    G,
+    HeartEgo,
+    SolarPlexus,
+    Sacral,
+    Spleen,
+    Root,
+}
+
+impl Center {
+    /// All nine centers, top to bottom
+    pub const ALL: [Center; 9] = [
+        Center::Head, Center::Ajna, Center::Throat, Center::G,
+        Center::HeartEgo, Center::SolarPlexus, Center::Sacral,
+        Center::Spleen, Center::Root,
+    ];
+
+    pub fn is_motor(&self) -> bool {
+        matches!(self, Center::Sacral | Center::SolarPlexus | Center::HeartEgo | Center::Root)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Center::Head => "Head",
+            Center::Ajna => "Ajna",
+            Center::Throat => "Throat",
+            Center::G => "G (Self)",
+            Center::HeartEgo => "Heart/Ego",
+            Center::SolarPlexus => "Solar Plexus",
+            Center::Sacral => "Sacral",
+            Center::Spleen => "Spleen",
+            Center::Root => "Root",
+        }
+    }
+}
+
+/// Circuit group a channel belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum Circuit {
+    Individual,
+    Collective,
+    Tribal,
+    Integration,
+}
+
+impl Circuit {
+    pub const ALL: [Circuit; 4] = [Circuit::Individual, Circuit::Collective, Circuit::Tribal, Circuit::Integration];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Circuit::Individual => "Individual",
+            Circuit::Collective => "Collective",
+            Circuit::Tribal => "Tribal",
+            Circuit::Integration => "Integration",
+        }
+    }
+}
+
+/// Sub-circuit within a circuit group (Integration has none)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub enum SubCircuit {
+    Knowing,
+    Centering,
+    /// Collective Understanding
+    Logic,
+    /// Collective Abstract
+    Sensing,
+    Ego,
+    Defense,
+}
+
+impl SubCircuit {
+    pub const ALL: [SubCircuit; 6] = [
+        SubCircuit::Knowing, SubCircuit::Centering, SubCircuit::Logic,
+        SubCircuit::Sensing, SubCircuit::Ego, SubCircuit::Defense,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SubCircuit::Knowing => "Knowing",
+            SubCircuit::Centering => "Centering",
+            SubCircuit::Logic => "Logic",
+            SubCircuit::Sensing => "Sensing",
+            SubCircuit::Ego => "Ego",
+            SubCircuit::Defense => "Defense",
+        }
+    }
+
+    pub fn circuit(&self) -> Circuit {
+        match self {
+            SubCircuit::Knowing | SubCircuit::Centering => Circuit::Individual,
+            SubCircuit::Logic | SubCircuit::Sensing => Circuit::Collective,
+            SubCircuit::Ego | SubCircuit::Defense => Circuit::Tribal,
+        }
+    }
+}
+
+/// All 36 channels with their gate pairs and center connections
+pub struct Channel {
+    pub gate1: u8,
+    pub gate2: u8,
+    pub name: &'static str,
+    pub center1: Center,
+    pub center2: Center,
+    pub circuit: Circuit,
+    pub sub_circuit: Option<SubCircuit>,
+}
+
+/// Which center a gate belongs to, or `None` outside 1..=64
+pub fn gate_center(gate: u8) -> Option<Center> {
+    match gate {
+        64 | 61 | 63 => Some(Center::Head),
+        47 | 24 | 4 | 17 | 43 | 11 => Some(Center::Ajna),
+        62 | 23 | 56 | 35 | 12 | 45 | 33 | 8 | 31 | 20 | 16 => Some(Center::Throat),
+        1 | 13 | 25 | 46 | 2 | 15 | 10 | 7 => Some(Center::G),
+        21 | 40 | 26 | 51 => Some(Center::HeartEgo),
+        36 | 22 | 37 | 6 | 49 | 55 | 30 => Some(Center::SolarPlexus),
+        5 | 14 | 29 | 59 | 9 | 3 | 42 | 27 | 34 => Some(Center::Sacral),
+        48 | 57 | 44 | 50 | 32 | 28 | 18 => Some(Center::Spleen),
+        58 | 38 | 54 | 53 | 60 | 52 | 19 | 39 | 41 => Some(Center::Root),
+        _ => None,
+    }
+}
+
+/// Circuits of the channels a gate takes part in, without duplicates
+pub fn gate_circuits(gate: u8) -> Vec<Circuit> {
+    let mut circuits: Vec<Circuit> = all_channels().iter()
+        .filter(|ch| ch.gate1 == gate || ch.gate2 == gate)
+        .map(|ch| ch.circuit)
+        .collect();
+    circuits.sort();
+    circuits.dedup();
+    circuits
+}
+
+/// The 36 channels of the bodygraph
+pub fn all_channels() -> Vec<Channel> {
+    vec![
+        Channel { gate1: 1, gate2: 8, name: "Inspiration", center1: Center::G, center2: Center::Throat, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 2, gate2: 14, name: "The Beat", center1: Center::G, center2: Center::Sacral, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 3, gate2: 60, name: "Mutation", center1: Center::Sacral, center2: Center::Root, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 4, gate2: 63, name: "Logic", center1: Center::Ajna, center2: Center::Head, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 5, gate2: 15, name: "Rhythms", center1: Center::Sacral, center2: Center::G, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 6, gate2: 59, name: "Intimacy", center1: Center::SolarPlexus, center2: Center::Sacral, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Defense) },
+        Channel { gate1: 7, gate2: 31, name: "The Alpha", center1: Center::G, center2: Center::Throat, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 9, gate2: 52, name: "Concentration", center1: Center::Sacral, center2: Center::Root, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 10, gate2: 20, name: "Awakening", center1: Center::G, center2: Center::Throat, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 10, gate2: 34, name: "Exploration", center1: Center::G, center2: Center::Sacral, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 10, gate2: 57, name: "Perfected Form", center1: Center::G, center2: Center::Spleen, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 11, gate2: 56, name: "Curiosity", center1: Center::Ajna, center2: Center::Throat, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 12, gate2: 22, name: "Openness", center1: Center::Throat, center2: Center::SolarPlexus, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 13, gate2: 33, name: "The Prodigal", center1: Center::G, center2: Center::Throat, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 16, gate2: 48, name: "The Wavelength", center1: Center::Throat, center2: Center::Spleen, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 17, gate2: 62, name: "Acceptance", center1: Center::Ajna, center2: Center::Throat, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 18, gate2: 58, name: "Judgement", center1: Center::Spleen, center2: Center::Root, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Logic) },
+        Channel { gate1: 19, gate2: 49, name: "Synthesis", center1: Center::Root, center2: Center::SolarPlexus, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Ego) },
+        Channel { gate1: 20, gate2: 34, name: "Charisma", center1: Center::Throat, center2: Center::Sacral, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 20, gate2: 57, name: "The Brainwave", center1: Center::Throat, center2: Center::Spleen, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 21, gate2: 45, name: "Money Line", center1: Center::HeartEgo, center2: Center::Throat, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Ego) },
+        Channel { gate1: 23, gate2: 43, name: "Structuring", center1: Center::Throat, center2: Center::Ajna, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 24, gate2: 61, name: "Awareness", center1: Center::Ajna, center2: Center::Head, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 25, gate2: 51, name: "Initiation", center1: Center::G, center2: Center::HeartEgo, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Centering) },
+        Channel { gate1: 26, gate2: 44, name: "Surrender", center1: Center::HeartEgo, center2: Center::Spleen, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Ego) },
+        Channel { gate1: 27, gate2: 50, name: "Preservation", center1: Center::Sacral, center2: Center::Spleen, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Defense) },
+        Channel { gate1: 28, gate2: 38, name: "Struggle", center1: Center::Spleen, center2: Center::Root, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 29, gate2: 46, name: "Discovery", center1: Center::Sacral, center2: Center::G, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 30, gate2: 41, name: "Recognition", center1: Center::SolarPlexus, center2: Center::Root, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 32, gate2: 54, name: "Transformation", center1: Center::Spleen, center2: Center::Root, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Ego) },
+        Channel { gate1: 34, gate2: 57, name: "Power", center1: Center::Sacral, center2: Center::Spleen, circuit: Circuit::Integration, sub_circuit: None },
+        Channel { gate1: 35, gate2: 36, name: "Transitoriness", center1: Center::Throat, center2: Center::SolarPlexus, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 37, gate2: 40, name: "Community", center1: Center::SolarPlexus, center2: Center::HeartEgo, circuit: Circuit::Tribal, sub_circuit: Some(SubCircuit::Ego) },
+        Channel { gate1: 39, gate2: 55, name: "Emoting", center1: Center::Root, center2: Center::SolarPlexus, circuit: Circuit::Individual, sub_circuit: Some(SubCircuit::Knowing) },
+        Channel { gate1: 42, gate2: 53, name: "Maturation", center1: Center::Sacral, center2: Center::Root, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+        Channel { gate1: 47, gate2: 64, name: "Abstraction", center1: Center::Ajna, center2: Center::Head, circuit: Circuit::Collective, sub_circuit: Some(SubCircuit::Sensing) },
+    ]
+}
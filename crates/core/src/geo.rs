@@ -0,0 +1,356 @@
+//! Offline geocoding of birth locations.
+//!
+//! Place names are matched against a gazetteer: a small bundled city list by
+//! default, or a full GeoNames cities dump (`cities15000.txt` etc.) loaded
+//! from disk. Each place carries coordinates and its IANA time zone, so the
+//! birth zone follows from the location.
+
+use serde::Serialize;
+use crate::error::{HdError, Result};
+use crate::tz::Zone;
+
+/// Bundled city list (tab-separated, see header of the file)
+const BUNDLED: &str = include_str!("../assets/cities.tsv");
+
+/// ISO country codes to names, used for matching and display
+const COUNTRIES: &[(&str, &str)] = &[
+    ("AE", "United Arab Emirates"),
+    ("AR", "Argentina"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AX", "Åland Islands"),
+    ("BA", "Bosnia and Herzegovina"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BG", "Bulgaria"),
+    ("BR", "Brazil"),
+    ("BY", "Belarus"),
+    ("CA", "Canada"),
+    ("CH", "Switzerland"),
+    ("CL", "Chile"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CR", "Costa Rica"),
+    ("CU", "Cuba"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DK", "Denmark"),
+    ("EC", "Ecuador"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("ES", "Spain"),
+    ("ET", "Ethiopia"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GB", "United Kingdom"),
+    ("GH", "Ghana"),
+    ("GR", "Greece"),
+    ("HK", "Hong Kong"),
+    ("HR", "Croatia"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IN", "India"),
+    ("IR", "Iran"),
+    ("IS", "Iceland"),
+    ("IT", "Italy"),
+    ("JM", "Jamaica"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KR", "South Korea"),
+    ("LB", "Lebanon"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("LV", "Latvia"),
+    ("MA", "Morocco"),
+    ("MD", "Moldova"),
+    ("MT", "Malta"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("NG", "Nigeria"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NP", "Nepal"),
+    ("NZ", "New Zealand"),
+    ("PE", "Peru"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PT", "Portugal"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russia"),
+    ("SA", "Saudi Arabia"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SI", "Slovenia"),
+    ("SK", "Slovakia"),
+    ("TH", "Thailand"),
+    ("TR", "Turkey"),
+    ("TW", "Taiwan"),
+    ("UA", "Ukraine"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("VE", "Venezuela"),
+    ("VN", "Vietnam"),
+    ("ZA", "South Africa"),
+];
+
+/// A resolved place
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Place {
+    pub name: String,
+    /// ISO 3166-1 alpha-2 country code
+    pub country_code: String,
+    /// GeoNames admin1 code (state abbreviation in the US)
+    pub admin1: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub population: u64,
+    /// IANA time zone name
+    pub timezone: String,
+}
+
+impl Place {
+    /// Country name, falling back to the code for countries not in the table
+    pub fn country(&self) -> &str {
+        country_name(&self.country_code).unwrap_or(&self.country_code)
+    }
+
+    /// Human-readable label, e.g. "Portland, OR, United States"
+    pub fn label(&self) -> String {
+        // Numeric admin1 codes mean nothing to readers; only show letter codes
+        if !self.admin1.is_empty() && self.admin1.chars().all(|c| c.is_ascii_alphabetic()) {
+            format!("{}, {}, {}", self.name, self.admin1, self.country())
+        } else {
+            format!("{}, {}", self.name, self.country())
+        }
+    }
+
+    /// Time zone of the place
+    pub fn zone(&self) -> Result<Zone> {
+        Zone::parse(&self.timezone)
+    }
+}
+
+struct Entry {
+    place: Place,
+    /// Normalized primary and alternate names
+    names: Vec<String>,
+}
+
+/// A searchable collection of places
+pub struct Gazetteer {
+    entries: Vec<Entry>,
+}
+
+impl Gazetteer {
+    /// The city list bundled with the crate
+    pub fn bundled() -> Gazetteer {
+        let entries = BUNDLED.lines()
+            .filter(|l| !l.starts_with('#') && !l.trim().is_empty())
+            .filter_map(parse_bundled_line)
+            .collect();
+        Gazetteer { entries }
+    }
+
+    /// Load a GeoNames cities dump (tab-separated, 19 columns)
+    pub fn load_geonames(path: &str) -> Result<Gazetteer> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| HdError::Gazetteer(format!("{}: {}", path, e)))?;
+        Self::from_geonames(&text)
+    }
+
+    /// Parse GeoNames cities dump text
+    pub fn from_geonames(text: &str) -> Result<Gazetteer> {
+        let entries: Vec<Entry> = text.lines().filter_map(parse_geonames_line).collect();
+        if entries.is_empty() {
+            return Err(HdError::Gazetteer("no places found in GeoNames data".to_string()));
+        }
+        Ok(Gazetteer { entries })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// All places matching `query`, best match first.
+    ///
+    /// The query is `name[, qualifier...]` where qualifiers are country names,
+    /// country codes or admin1 codes ("Portland, OR", "London, Canada").
+    /// Names match exactly or within a small edit distance.
+    pub fn search(&self, query: &str) -> Vec<&Place> {
+        self.matches(query).into_iter().map(|(_, p)| p).collect()
+    }
+
+    /// Resolve `query` to exactly one place.
+    ///
+    /// Fails with [`HdError::AmbiguousLocation`] when several places match
+    /// equally well, listing them so the caller can add a qualifier.
+    pub fn resolve(&self, query: &str) -> Result<Place> {
+        let hits = self.matches(query);
+        let best_dist = match hits.first() {
+            Some((d, _)) => *d,
+            None => return Err(HdError::UnknownLocation(query.to_string())),
+        };
+        let best: Vec<&Place> = hits.iter()
+            .take_while(|(d, _)| *d == best_dist)
+            .map(|(_, p)| *p)
+            .collect();
+        match best.as_slice() {
+            [place] => Ok((*place).clone()),
+            _ => Err(HdError::AmbiguousLocation {
+                query: query.to_string(),
+                candidates: best.iter().take(10).map(|p| describe(p)).collect(),
+            }),
+        }
+    }
+
+    /// Matching places with their edit distance, sorted by distance then population
+    fn matches(&self, query: &str) -> Vec<(usize, &Place)> {
+        let mut parts = query.split(',').map(normalize).filter(|p| !p.is_empty());
+        let name = match parts.next() {
+            Some(n) => n,
+            None => return Vec::new(),
+        };
+        let qualifiers: Vec<String> = parts.collect();
+        let max_edits = max_edits(&name);
+
+        let mut hits: Vec<(usize, &Place)> = self.entries.iter()
+            .filter(|e| qualifiers.iter().all(|q| matches_qualifier(&e.place, q)))
+            .filter_map(|e| {
+                let dist = e.names.iter().map(|n| levenshtein(&name, n)).min()?;
+                (dist <= max_edits).then_some((dist, &e.place))
+            })
+            .collect();
+        hits.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.population.cmp(&a.1.population)));
+        hits
+    }
+}
+
+/// Candidate line for disambiguation output
+fn describe(p: &Place) -> String {
+    format!("{} ({:.4}, {:.4}, {})", p.label(), p.latitude, p.longitude, p.timezone)
+}
+
+fn country_name(code: &str) -> Option<&'static str> {
+    COUNTRIES.iter().find(|(c, _)| c.eq_ignore_ascii_case(code)).map(|(_, n)| *n)
+}
+
+fn matches_qualifier(place: &Place, q: &str) -> bool {
+    if q.eq_ignore_ascii_case(&place.country_code) || q.eq_ignore_ascii_case(&place.admin1) {
+        return true;
+    }
+    match country_name(&place.country_code) {
+        Some(country) => {
+            let country = normalize(country);
+            levenshtein(q, &country) <= max_edits(q).min(1)
+                || (q == "usa" && place.country_code == "US")
+                || (q == "uk" && place.country_code == "GB")
+        }
+        None => false,
+    }
+}
+
+/// Allowed typos for a name of this length
+fn max_edits(name: &str) -> usize {
+    match name.chars().count() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    }
+}
+
+/// Lowercase, fold common diacritics and collapse whitespace
+fn normalize(s: &str) -> String {
+    let folded: String = s.chars()
+        .flat_map(|c| c.to_lowercase())
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ā' => 'a',
+            'é' | 'è' | 'ê' | 'ë' | 'ē' => 'e',
+            'í' | 'ì' | 'î' | 'ï' | 'ī' => 'i',
+            'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' => 'o',
+            'ú' | 'ù' | 'û' | 'ü' | 'ū' => 'u',
+            'ç' => 'c',
+            'ñ' => 'n',
+            'ș' | 'ş' | 'š' => 's',
+            'ț' | 'ţ' => 't',
+            'ž' => 'z',
+            'č' | 'ć' => 'c',
+            'ł' => 'l',
+            '.' | '-' => ' ',
+            c => c,
+        })
+        .collect();
+    folded.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+fn entry(place: Place, names: impl Iterator<Item = String>) -> Entry {
+    let mut names: Vec<String> = std::iter::once(normalize(&place.name))
+        .chain(names.map(|n| normalize(&n)))
+        .filter(|n| !n.is_empty())
+        .collect();
+    names.sort();
+    names.dedup();
+    Entry { place, names }
+}
+
+/// name, alternates, country, admin1, lat, lon, population, timezone
+fn parse_bundled_line(line: &str) -> Option<Entry> {
+    let f: Vec<&str> = line.split('\t').collect();
+    if f.len() != 8 {
+        return None;
+    }
+    let place = Place {
+        name: f[0].to_string(),
+        country_code: f[2].to_string(),
+        admin1: f[3].to_string(),
+        latitude: f[4].parse().ok()?,
+        longitude: f[5].parse().ok()?,
+        population: f[6].parse().ok()?,
+        timezone: f[7].to_string(),
+    };
+    let alternates: Vec<String> = f[1].split(';').map(str::to_string).collect();
+    Some(entry(place, alternates.into_iter()))
+}
+
+/// GeoNames "geoname" table row
+fn parse_geonames_line(line: &str) -> Option<Entry> {
+    let f: Vec<&str> = line.split('\t').collect();
+    if f.len() < 19 || f[17].is_empty() {
+        return None;
+    }
+    let place = Place {
+        name: f[1].to_string(),
+        country_code: f[8].to_string(),
+        admin1: f[10].to_string(),
+        latitude: f[4].parse().ok()?,
+        longitude: f[5].parse().ok()?,
+        population: f[14].parse().unwrap_or(0),
+        timezone: f[17].to_string(),
+    };
+    let names: Vec<String> = std::iter::once(f[2].to_string())
+        .chain(f[3].split(',').map(str::to_string))
+        .collect();
+    Some(entry(place, names.into_iter()))
+}
@@ -0,0 +1,115 @@
+//! iCalendar (RFC 5545) export of transit events.
+//!
+//! Ingresses become instantaneous events and forecast windows become events
+//! spanning the window. UIDs are derived from the event itself, so a
+//! re-exported calendar updates existing events instead of duplicating them.
+
+use chrono::{DateTime, Utc};
+use crate::gates::gate_name;
+use crate::ingress::Ingress;
+use crate::transit::{CompletedChannel, TransitWindow};
+
+const PRODID: &str = "-//hd//Human Design transits//EN";
+/// Maximum line length in octets before folding
+const LINE_LIMIT: usize = 75;
+
+/// Calendar with one event per ingress
+pub fn ingress_calendar(ingresses: &[Ingress], stamp: DateTime<Utc>) -> String {
+    calendar("Human Design transits", ingresses.iter().map(|i| {
+        let change = if i.is_gate_change() { "Gate" } else { "Line" };
+        let summary = format!("{} enters {} {}.{} {}",
+            i.planet, change, i.gate, i.line, gate_name(i.gate));
+        let description = format!("{} moves{} from Gate {}.{} ({}) into Gate {}.{} ({}).",
+            i.planet,
+            if i.retrograde { " retrograde" } else { "" },
+            i.from_gate, i.from_line, gate_name(i.from_gate),
+            i.gate, i.line, gate_name(i.gate));
+        let uid = format!("{}-{}-{}.{}", slug(i.planet), timestamp(i.at), i.gate, i.line);
+        event(&uid, stamp, i.at, None, &summary, &description)
+    }))
+}
+
+/// Calendar with one event per forecast window
+pub fn forecast_calendar(windows: &[TransitWindow], stamp: DateTime<Utc>) -> String {
+    calendar("Human Design transit forecast", windows.iter().map(|w| {
+        let ch = &w.channel;
+        let summary = format!("{} completes {}-{} {}", w.planet, ch.gate1, ch.gate2, ch.name);
+        let mut description = describe(w.planet, ch);
+        if !w.opened_centers.is_empty() {
+            let names: Vec<&str> = w.opened_centers.iter().map(|c| c.name()).collect();
+            description.push_str(&format!(" Defines the open {}.", names.join(" and ")));
+        }
+        let uid = format!("{}-{}-{}-{}", slug(w.planet), timestamp(w.start), ch.gate1, ch.gate2);
+        event(&uid, stamp, w.start, Some(w.end), &summary, &description)
+    }))
+}
+
+/// Description of a completed channel
+fn describe(planet: &str, ch: &CompletedChannel) -> String {
+    format!("{} in Gate {} ({}) meets natal Gate {} ({}), completing the Channel of {} ({} to {}).",
+        planet,
+        ch.transit_gate, gate_name(ch.transit_gate),
+        ch.natal_gate, gate_name(ch.natal_gate),
+        ch.name, ch.center1.name(), ch.center2.name())
+}
+
+fn calendar(name: &str, events: impl Iterator<Item = String>) -> String {
+    let mut out = String::new();
+    for line in ["BEGIN:VCALENDAR", "VERSION:2.0", &format!("PRODID:{}", PRODID), "CALSCALE:GREGORIAN"] {
+        push_line(&mut out, line);
+    }
+    push_line(&mut out, &format!("X-WR-CALNAME:{}", escape(name)));
+    for event in events {
+        out.push_str(&event);
+    }
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+fn event(uid: &str, stamp: DateTime<Utc>, start: DateTime<Utc>, end: Option<DateTime<Utc>>, summary: &str, description: &str) -> String {
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VEVENT");
+    push_line(&mut out, &format!("UID:{}@hd", uid));
+    push_line(&mut out, &format!("DTSTAMP:{}", timestamp(stamp)));
+    push_line(&mut out, &format!("DTSTART:{}", timestamp(start)));
+    if let Some(end) = end {
+        push_line(&mut out, &format!("DTEND:{}", timestamp(end)));
+    }
+    push_line(&mut out, &format!("SUMMARY:{}", escape(summary)));
+    push_line(&mut out, &format!("DESCRIPTION:{}", escape(description)));
+    push_line(&mut out, "TRANSP:TRANSPARENT");
+    push_line(&mut out, "END:VEVENT");
+    out
+}
+
+/// UTC date-time in basic format, e.g. `20261221T100000Z`
+fn timestamp(t: DateTime<Utc>) -> String {
+    t.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn slug(s: &str) -> String {
+    s.to_ascii_lowercase().replace(' ', "-")
+}
+
+/// Escape TEXT property values
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Append a content line, folded to 75 octets with CRLF line breaks
+fn push_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for ch in line.chars() {
+        if width + ch.len_utf8() > LINE_LIMIT {
+            out.push_str("\r\n ");
+            // The leading space of a continuation line counts toward its length
+            width = 1;
+        }
+        out.push(ch);
+        width += ch.len_utf8();
+    }
+    out.push_str("\r\n");
+}
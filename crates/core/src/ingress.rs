@@ -0,0 +1,127 @@
+//! Transit ingresses: exact instants a body enters a new gate or line.
+//!
+//! Each body's longitude is sampled at a step short enough that it cannot
+//! cross a whole line between samples, and every change of line is then
+//! bisected down to the millisecond. Crossings are detected by line index
+//! rather than by comparing longitudes, so retrograde motion (including the
+//! nodes, which are almost always retrograde) is handled the same way.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::chart::{body_longitude, body_names, instant_from_jd, julian_day, NodeModel};
+use crate::error::{HdError, Result};
+use crate::gates::{longitude_to_line_index, GATE_ORDER, LINES_PER_CIRCLE};
+
+/// Sampling step for the Moon in days (it moves up to ~0.65° per hour)
+const MOON_STEP: f64 = 1.0 / 24.0;
+/// Sampling step for every other body in days
+const STEP: f64 = 0.25;
+/// Bisection stops below this interval in days (~1 ms)
+const PRECISION: f64 = 1e-8;
+
+/// A body entering a new line, and possibly a new gate
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Ingress {
+    pub at: DateTime<Utc>,
+    pub planet: &'static str,
+    pub gate: u8,
+    pub line: u8,
+    pub from_gate: u8,
+    pub from_line: u8,
+    /// Entered moving backward through the mandala
+    pub retrograde: bool,
+}
+
+impl Ingress {
+    /// The body entered a new gate, not only a new line
+    pub fn is_gate_change(&self) -> bool {
+        self.gate != self.from_gate
+    }
+}
+
+/// Gate and line of a mandala line index
+fn gate_line(index: u16) -> (u8, u8) {
+    (GATE_ORDER[(index / 6) as usize], (index % 6) as u8 + 1)
+}
+
+/// All ingresses of all bodies between two instants, in chronological order
+pub fn ingresses(from: DateTime<Utc>, to: DateTime<Utc>, node: NodeModel) -> Result<Vec<Ingress>> {
+    if to <= from {
+        return Err(HdError::InvalidRange(format!("{} .. {}", from.to_rfc3339(), to.to_rfc3339())));
+    }
+    let (start, end) = (julian_day(from)?, julian_day(to)?);
+
+    let mut all = Vec::new();
+    for index in 0..body_names().count() {
+        all.extend(body_timeline(index, start, end, node, false)?);
+    }
+    // Stable, so simultaneous ingresses (Sun and Earth) stay in body order
+    all.sort_by_key(|i| i.at);
+    Ok(all)
+}
+
+/// Ingresses of the body at `index` in [`body_names`] order between two
+/// Julian Days, in chronological order. With `gates_only` line changes
+/// within a gate are neither bisected nor reported.
+pub(crate) fn body_timeline(index: usize, start: f64, end: f64, node: NodeModel, gates_only: bool) -> Result<Vec<Ingress>> {
+    let planet = body_names().nth(index).expect("body index in range");
+    let step = if planet == "Moon" { MOON_STEP } else { STEP };
+    let unit = if gates_only { 6 } else { 1 };
+    let mut out = Vec::new();
+    body_ingresses(index, planet, start, end, step, node, unit, &mut out)?;
+    Ok(out)
+}
+
+/// Ingresses of one body; `unit` is the number of lines that count as one
+/// step: 1 for every line, 6 for gates only
+#[allow(clippy::too_many_arguments)]
+fn body_ingresses(index: usize, planet: &'static str, start: f64, end: f64, step: f64, node: NodeModel, unit: u16, out: &mut Vec<Ingress>) -> Result<()> {
+    let line_at = |jd: f64| body_longitude(index, jd, node).map(longitude_to_line_index);
+
+    let mut t0 = start;
+    let mut line0 = line_at(t0)?;
+    while t0 < end {
+        let t1 = (t0 + step).min(end);
+        let line1 = line_at(t1)?;
+        // Several crossings within one step are found one after another
+        while line0 / unit != line1 / unit {
+            let (t, line) = first_change(&line_at, t0, line0, unit, t1)?;
+            let (gate, l) = gate_line(line);
+            let (from_gate, from_line) = gate_line(line0);
+            let forward = (line + LINES_PER_CIRCLE - line0) % LINES_PER_CIRCLE;
+            out.push(Ingress {
+                at: instant_from_jd(t),
+                planet,
+                gate,
+                line: l,
+                from_gate,
+                from_line,
+                retrograde: forward > LINES_PER_CIRCLE / 2,
+            });
+            t0 = t;
+            line0 = line;
+        }
+        t0 = t1;
+        line0 = line1;
+    }
+    Ok(())
+}
+
+/// Bisect for the first instant in (t0, t1] whose line differs from `line0`
+/// by at least a `unit`
+fn first_change(line_at: &impl Fn(f64) -> Result<u16>, t0: f64, line0: u16, unit: u16, t1: f64) -> Result<(f64, u16)> {
+    let (mut lo, mut hi) = (t0, t1);
+    let mut hi_line = line_at(hi)?;
+    while hi - lo > PRECISION {
+        let mid = (lo + hi) / 2.0;
+        let line = line_at(mid)?;
+        if line / unit == line0 / unit {
+            lo = mid;
+        } else {
+            hi = mid;
+            hi_line = line;
+        }
+    }
+    Ok((hi, hi_line))
+}
@@ -0,0 +1,52 @@
+//! Human Design chart engine.
+//!
+//! Computes planetary activations with the Swiss Ephemeris, analyzes them into
+//! type, authority, profile and definition, overlays transits and renders the
+//! bodygraph as SVG.
+//!
+//! ```no_run
+//! # fn main() -> hd_core::Result<()> {
+//! let chart = hd_core::calculate_chart(1985, 6, 15, 14.5, 2.0)?;
+//! let analysis = hd_core::analyze(&chart)?;
+//! println!("{} {}/{}", analysis.hd_type.name(), analysis.profile.0, analysis.profile.1);
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod chart;
+pub mod composite;
+pub mod crosses;
+pub mod document;
+pub mod error;
+pub mod gates;
+pub mod geo;
+pub mod ical;
+pub mod ingress;
+pub mod parse;
+pub mod penta;
+pub mod rectify;
+pub mod returns;
+pub mod search;
+pub mod svg;
+pub mod transit;
+pub mod types;
+pub mod tz;
+pub mod variables;
+
+pub use chart::{calculate_chart, calculate_chart_at, calculate_chart_with, calculate_transit, calculate_transit_at, Activation, Chart, ChartOptions, ExtraBody, NodeModel};
+pub use composite::{composite, Composite, CompositeChannel, ConnectionKind, Person};
+pub use crosses::{Angle, IncarnationCross, Quarter};
+pub use document::{BirthInfo, ChartDocument, CompositeDocument, ForecastDocument, PentaDocument, RectifyDocument, ReturnDocument, SearchDocument, TimelineDocument, TransitDocument};
+pub use error::{HdError, Result};
+pub use gates::{gate_name, Center, Channel, Circuit, SubCircuit};
+pub use geo::{Gazetteer, Place};
+pub use ingress::{ingresses, Ingress};
+pub use penta::{penta, Penta, PentaGate, PentaRole};
+pub use rectify::{rectify, ActivationChange, Boundary, ChartWindow, Rectification};
+pub use returns::{returns, Cycle, PlanetReturn};
+pub use search::{search, ActivationConstraint, Query, SearchMatch, Side};
+pub use svg::{generate_composite_svg, generate_penta_svg, generate_svg};
+pub use transit::{forecast, overlay_transits, CompletedChannel, TransitOverlay, TransitWindow};
+pub use types::{analyze, analyze_gates, BridgingGate, ChartAnalysis, CircuitryBalance, DefinedChannel, Definition, GateAnalysis, HdType};
+pub use tz::{DstPolicy, ResolvedOffset, Zone};
+pub use variables::{Arrow, Variable, Variables};
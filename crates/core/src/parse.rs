@@ -0,0 +1,132 @@
+//! Parsing of user-supplied birth data and instant strings.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use crate::error::{HdError, Result};
+
+/// Parse a `YYYY-MM-DD` date into (year, month, day)
+pub fn parse_date(s: &str) -> Result<(i32, u32, u32)> {
+    let err = || HdError::InvalidDate(s.to_string());
+    // Allow a leading minus for BCE years
+    let (sign, rest) = match s.trim().strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, s.trim()),
+    };
+    let parts: Vec<&str> = rest.split('-').collect();
+    if parts.len() != 3 {
+        return Err(err());
+    }
+    let year: i32 = parts[0].parse().map_err(|_| err())?;
+    let month: u32 = parts[1].parse().map_err(|_| err())?;
+    let day: u32 = parts[2].parse().map_err(|_| err())?;
+    Ok((sign * year, month, day))
+}
+
+/// Parse an `HH:MM` or `HH:MM:SS` time into decimal hours
+pub fn parse_time(s: &str) -> Result<f64> {
+    let err = || HdError::InvalidTime(s.to_string());
+    let parts: Vec<&str> = s.trim().split(':').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return Err(err());
+    }
+    let hour: u32 = parts[0].parse().map_err(|_| err())?;
+    let minute: u32 = parts[1].parse().map_err(|_| err())?;
+    let second: f64 = match parts.get(2) {
+        Some(p) => p.parse().map_err(|_| err())?,
+        None => 0.0,
+    };
+    if hour > 23 || minute > 59 || !(0.0..60.0).contains(&second) {
+        return Err(err());
+    }
+    Ok(hour as f64 + minute as f64 / 60.0 + second / 3600.0)
+}
+
+/// Parse a UTC offset in hours: `+2`, `-5.5`, `+05:30`
+pub fn parse_tz_offset(s: &str) -> Result<f64> {
+    let err = || HdError::InvalidTimezone(s.to_string());
+    let trimmed = s.trim();
+    let (sign, rest) = if let Some(rest) = trimmed.strip_prefix('-') {
+        (-1.0, rest)
+    } else {
+        (1.0, trimmed.strip_prefix('+').unwrap_or(trimmed))
+    };
+    if rest.starts_with(['+', '-']) {
+        return Err(err());
+    }
+    let hours = match rest.split_once(':') {
+        Some((h, m)) => {
+            let h: u32 = h.parse().map_err(|_| err())?;
+            let m: u32 = m.parse().map_err(|_| err())?;
+            if m > 59 {
+                return Err(err());
+            }
+            h as f64 + m as f64 / 60.0
+        }
+        None => rest.parse::<f64>().map_err(|_| err())?,
+    };
+    let offset = sign * hours;
+    if !offset.is_finite() || !(-14.0..=14.0).contains(&offset) {
+        return Err(err());
+    }
+    Ok(offset)
+}
+
+/// Parse an instant relative to `now`:
+///
+/// - `now`
+/// - a signed offset with unit `m`, `h`, `d` or `w`: `+7d`, `-12h`
+/// - `YYYY-MM-DD[THH:MM[:SS]][Z|±HH:MM]`, also with a space instead of `T`;
+///   without an offset the time is taken as UTC
+pub fn parse_instant(s: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let err = || HdError::InvalidInstant(s.to_string());
+    let trimmed = s.trim();
+    if trimmed.eq_ignore_ascii_case("now") {
+        return Ok(now);
+    }
+    if let Some(offset) = parse_relative(trimmed) {
+        return now.checked_add_signed(offset).ok_or_else(err);
+    }
+
+    let (date, time) = match trimmed.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (trimmed, None),
+    };
+    let (year, month, day) = parse_date(date).map_err(|_| err())?;
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(err)?;
+
+    let (hour, offset) = match time {
+        None => (0.0, 0.0),
+        Some(time) => {
+            let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+                (clock, 0.0)
+            } else if let Some(pos) = time.rfind(['+', '-']) {
+                (&time[..pos], parse_tz_offset(&time[pos..]).map_err(|_| err())?)
+            } else {
+                (time, 0.0)
+            };
+            (parse_time(clock).map_err(|_| err())?, offset)
+        }
+    };
+    let seconds = ((hour - offset) * 3600.0).round() as i64;
+    let midnight = date.and_hms_opt(0, 0, 0).ok_or_else(err)?.and_utc();
+    midnight.checked_add_signed(Duration::seconds(seconds)).ok_or_else(err)
+}
+
+/// `+7d`, `-12h`, `+30m`, `+2w`
+fn parse_relative(s: &str) -> Option<Duration> {
+    let sign = match s.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let body = &s[1..];
+    let unit = body.chars().last()?;
+    let amount: i64 = body[..body.len() - unit.len_utf8()].parse().ok()?;
+    let duration = match unit {
+        'm' => Duration::try_minutes(amount)?,
+        'h' => Duration::try_hours(amount)?,
+        'd' => Duration::try_days(amount)?,
+        'w' => Duration::try_weeks(amount)?,
+        _ => return None,
+    };
+    Some(duration * sign)
+}
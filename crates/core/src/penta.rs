@@ -0,0 +1,130 @@
+//! Penta: group dynamics of 3 to 5 people.
+//!
+//! The Penta is formed by the six non-integration channels between the G,
+//! Sacral and Throat centers. Its twelve gates are filled by whoever in the
+//! group activates them; each channel carries one group role.
+
+use serde::Serialize;
+use crate::chart::Chart;
+use crate::error::{HdError, Result};
+use crate::gates::{all_channels, gate_center, gate_name, Center, Channel, Circuit};
+
+/// Smallest group that forms a Penta
+pub const MIN_MEMBERS: usize = 3;
+/// Largest group that forms a Penta
+pub const MAX_MEMBERS: usize = 5;
+
+/// One of the twelve Penta gates
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PentaGate {
+    pub gate: u8,
+    pub center: Center,
+    pub keynote: &'static str,
+    /// Indices of the members activating the gate
+    pub members: Vec<usize>,
+}
+
+impl PentaGate {
+    pub fn is_filled(&self) -> bool {
+        !self.members.is_empty()
+    }
+}
+
+/// A Penta channel and the group role it carries
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PentaRole {
+    pub role: &'static str,
+    pub gate1: u8,
+    pub gate2: u8,
+    pub channel_name: &'static str,
+    /// Both gates are filled by the group
+    pub covered: bool,
+    /// Indices of the members holding the whole channel themselves
+    pub held_by: Vec<usize>,
+}
+
+/// Penta of a group
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Penta {
+    pub size: usize,
+    /// The twelve gates, Throat to Sacral
+    pub gates: Vec<PentaGate>,
+    pub roles: Vec<PentaRole>,
+    pub filled: Vec<u8>,
+    pub missing: Vec<u8>,
+}
+
+impl Penta {
+    pub fn gate(&self, gate: u8) -> Option<&PentaGate> {
+        self.gates.iter().find(|g| g.gate == gate)
+    }
+}
+
+/// Role carried by a Penta channel
+fn role(gate1: u8, gate2: u8) -> &'static str {
+    match (gate1.min(gate2), gate1.max(gate2)) {
+        (7, 31) => "Leadership",
+        (1, 8) => "Creative Direction",
+        (13, 33) => "Memory",
+        (5, 15) => "Flow",
+        (2, 14) => "Resources",
+        (29, 46) => "Commitment",
+        _ => "",
+    }
+}
+
+/// The twelve Penta gates, Throat to Sacral
+const GATES: [u8; 12] = [31, 8, 33, 7, 1, 13, 15, 2, 46, 5, 14, 29];
+
+/// The six Penta channels: G–Throat and G–Sacral, without the integration
+/// channels that also join these centers
+pub fn penta_channels() -> Vec<Channel> {
+    let penta_centers = [Center::G, Center::Sacral, Center::Throat];
+    all_channels().into_iter()
+        .filter(|ch| ch.circuit != Circuit::Integration
+            && penta_centers.contains(&ch.center1)
+            && penta_centers.contains(&ch.center2))
+        .collect()
+}
+
+/// Analyze the Penta of 3 to 5 charts
+pub fn penta(charts: &[Chart]) -> Result<Penta> {
+    if !(MIN_MEMBERS..=MAX_MEMBERS).contains(&charts.len()) {
+        return Err(HdError::GroupSize(charts.len()));
+    }
+    let member_gates: Vec<_> = charts.iter().map(|c| c.gates()).collect();
+    let members_with = |gate: u8| -> Vec<usize> {
+        (0..charts.len()).filter(|&i| member_gates[i].contains(&gate)).collect()
+    };
+
+    let channels = penta_channels();
+    let gates: Vec<PentaGate> = GATES.iter()
+        .map(|&gate| PentaGate {
+            gate,
+            center: gate_center(gate).expect("Penta gates are valid gates"),
+            keynote: gate_name(gate),
+            members: members_with(gate),
+        })
+        .collect();
+
+    let roles = channels.iter()
+        .map(|ch| PentaRole {
+            role: role(ch.gate1, ch.gate2),
+            gate1: ch.gate1,
+            gate2: ch.gate2,
+            channel_name: ch.name,
+            covered: !members_with(ch.gate1).is_empty() && !members_with(ch.gate2).is_empty(),
+            held_by: (0..charts.len())
+                .filter(|&i| member_gates[i].contains(&ch.gate1) && member_gates[i].contains(&ch.gate2))
+                .collect(),
+        })
+        .collect();
+
+    let filled = gates.iter().filter(|g| g.is_filled()).map(|g| g.gate).collect();
+    let missing = gates.iter().filter(|g| !g.is_filled()).map(|g| g.gate).collect();
+
+    Ok(Penta { size: charts.len(), gates, roles, filled, missing })
+}
@@ -0,0 +1,159 @@
+//! Birth-time rectification: how a chart changes across a window of
+//! possible birth times.
+//!
+//! The window is sampled at a step shorter than any activation can take to
+//! cross a line, and each change is bisected down to the millisecond, as in
+//! [`crate::ingress`]. Both Personality and Design activations are tracked,
+//! since the Design moment moves with the birth time.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::chart::{chart_at_jd, instant_from_jd, julian_day, Chart, ChartOptions};
+use crate::error::{HdError, Result};
+use crate::gates::longitude_to_line_index;
+use crate::types::{analyze, ChartAnalysis};
+
+/// Sampling step in days; the Moon needs ~1.7 hours to cross a line
+const STEP: f64 = 10.0 / 1440.0;
+/// Bisection stops below this interval in days (~1 ms)
+const PRECISION: f64 = 1e-8;
+
+/// One activation entering a new line at a boundary
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivationChange {
+    pub planet: &'static str,
+    /// Design (unconscious) rather than Personality activation
+    pub design: bool,
+    pub gate: u8,
+    pub line: u8,
+    pub from_gate: u8,
+    pub from_line: u8,
+}
+
+/// Birth instant at which one or more activations change line
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Boundary {
+    /// First instant with the new activations
+    pub at: DateTime<Utc>,
+    pub changes: Vec<ActivationChange>,
+}
+
+/// Part of the window over which type, authority, profile, cross and
+/// channels stay the same
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartWindow {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    /// Chart at the start of the sub-window
+    pub chart: Chart,
+    pub analysis: ChartAnalysis,
+}
+
+/// Every line boundary in a window of birth times and the distinct charts
+/// between them
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rectification {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub boundaries: Vec<Boundary>,
+    pub windows: Vec<ChartWindow>,
+}
+
+/// Line index of every Personality then Design activation
+fn line_indices(chart: &Chart) -> Vec<u16> {
+    chart.personality.iter()
+        .chain(&chart.design)
+        .map(|a| longitude_to_line_index(a.longitude))
+        .collect()
+}
+
+/// Same reading: type, authority, profile, cross and channels
+fn same_reading(a: &ChartAnalysis, b: &ChartAnalysis) -> bool {
+    let channels = |x: &ChartAnalysis| x.defined_channels.iter().map(|ch| (ch.gate1, ch.gate2)).collect::<Vec<_>>();
+    a.hd_type.name() == b.hd_type.name()
+        && a.authority == b.authority
+        && a.profile == b.profile
+        && a.incarnation_cross == b.incarnation_cross
+        && channels(a) == channels(b)
+}
+
+/// Scan birth times from `from` to `to` for line boundaries and group the
+/// resulting charts into sub-windows with the same reading
+pub fn rectify(from: DateTime<Utc>, to: DateTime<Utc>, options: &ChartOptions) -> Result<Rectification> {
+    if to <= from {
+        return Err(HdError::InvalidRange(format!("{} .. {}", from.to_rfc3339(), to.to_rfc3339())));
+    }
+    let (start, end) = (julian_day(from)?, julian_day(to)?);
+    let chart_at = |jd: f64| chart_at_jd(jd, options);
+
+    // Segments of constant activations: (start jd, chart)
+    let mut segments = vec![(start, chart_at(start)?)];
+    let mut boundaries = Vec::new();
+    let mut t0 = start;
+    while t0 < end {
+        let t1 = (t0 + STEP).min(end);
+        let lines1 = line_indices(&chart_at(t1)?);
+        // Several boundaries within one step are found one after another
+        while line_indices(&segments.last().expect("first segment").1) != lines1 {
+            let before = &segments.last().expect("first segment").1;
+            let (t, chart) = first_change(&chart_at, t0, &line_indices(before), t1)?;
+            boundaries.push(Boundary { at: instant_from_jd(t), changes: changes(before, &chart) });
+            segments.push((t, chart));
+            t0 = t;
+        }
+        t0 = t1;
+    }
+
+    let mut windows: Vec<ChartWindow> = Vec::new();
+    for (n, (jd, chart)) in segments.into_iter().enumerate() {
+        let analysis = analyze(&chart)?;
+        let at = if n == 0 { from } else { instant_from_jd(jd) };
+        match windows.last_mut() {
+            Some(last) if same_reading(&last.analysis, &analysis) => {}
+            Some(last) => {
+                last.to = at;
+                windows.push(ChartWindow { from: at, to, chart, analysis });
+            }
+            None => windows.push(ChartWindow { from: at, to, chart, analysis }),
+        }
+    }
+    Ok(Rectification { from, to, boundaries, windows })
+}
+
+/// Activations whose line differs between two charts
+fn changes(before: &Chart, after: &Chart) -> Vec<ActivationChange> {
+    let sides = [(false, &before.personality, &after.personality), (true, &before.design, &after.design)];
+    sides.into_iter()
+        .flat_map(|(design, b, a)| b.iter().zip(a.iter()).map(move |(b, a)| (design, b, a)))
+        .filter(|(_, b, a)| (b.gate, b.line) != (a.gate, a.line))
+        .map(|(design, b, a)| ActivationChange {
+            planet: a.planet,
+            design,
+            gate: a.gate,
+            line: a.line,
+            from_gate: b.gate,
+            from_line: b.line,
+        })
+        .collect()
+}
+
+/// Bisect for the first instant in (t0, t1] whose lines differ from `lines0`
+fn first_change(chart_at: &impl Fn(f64) -> Result<Chart>, t0: f64, lines0: &[u16], t1: f64) -> Result<(f64, Chart)> {
+    let (mut lo, mut hi) = (t0, t1);
+    let mut hi_chart = chart_at(hi)?;
+    while hi - lo > PRECISION {
+        let mid = (lo + hi) / 2.0;
+        let chart = chart_at(mid)?;
+        if line_indices(&chart) == lines0 {
+            lo = mid;
+        } else {
+            hi = mid;
+            hi_chart = chart;
+        }
+    }
+    Ok((hi, hi_chart))
+}
@@ -0,0 +1,166 @@
+//! Planetary returns and life-cycle charts.
+//!
+//! A return is the instant a transiting body comes back to its natal
+//! longitude (or, for the Uranus opposition, reaches the opposite point).
+//! Slow bodies can cross the point up to three times around a station, so
+//! every exact crossing in the search range is reported.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use swiss_eph::safe::Planet;
+use crate::chart::{calculate_chart_at_with, instant_from_jd, julian_day, planet_longitude, Chart, ChartOptions};
+use crate::error::{HdError, Result};
+use crate::transit::{overlay_transits, CompletedChannel};
+use crate::types::{analyze, analyze_gates, ChartAnalysis};
+
+/// Bisection stops below this interval in days (~1 ms)
+const PRECISION: f64 = 1e-8;
+
+/// Life cycle to search for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Cycle {
+    SolarReturn,
+    SaturnReturn,
+    UranusOpposition,
+    ChironReturn,
+}
+
+impl Cycle {
+    pub const ALL: [Cycle; 4] = [Cycle::SolarReturn, Cycle::SaturnReturn, Cycle::UranusOpposition, Cycle::ChironReturn];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Cycle::SolarReturn => "Solar Return",
+            Cycle::SaturnReturn => "Saturn Return",
+            Cycle::UranusOpposition => "Uranus Opposition",
+            Cycle::ChironReturn => "Chiron Return",
+        }
+    }
+
+    /// Transiting body, e.g. "Saturn"
+    pub fn planet(&self) -> &'static str {
+        match self {
+            Cycle::SolarReturn => "Sun",
+            Cycle::SaturnReturn => "Saturn",
+            Cycle::UranusOpposition => "Uranus",
+            Cycle::ChironReturn => "Chiron",
+        }
+    }
+
+    /// Cycle of a body name, case-insensitive ("sun", "saturn", ...)
+    pub fn from_planet(name: &str) -> Option<Cycle> {
+        Cycle::ALL.into_iter().find(|c| c.planet().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Approximate age in years at the first occurrence
+    pub fn first_age(&self) -> f64 {
+        match self {
+            Cycle::SolarReturn => 1.0,
+            Cycle::SaturnReturn => 29.5,
+            Cycle::UranusOpposition => 42.0,
+            Cycle::ChironReturn => 50.7,
+        }
+    }
+
+    fn body(&self) -> Planet {
+        match self {
+            Cycle::SolarReturn => Planet::Sun,
+            Cycle::SaturnReturn => Planet::Saturn,
+            Cycle::UranusOpposition => Planet::Uranus,
+            Cycle::ChironReturn => Planet::Chiron,
+        }
+    }
+
+    /// Angle from the natal longitude that completes the cycle
+    fn angle(&self) -> f64 {
+        match self {
+            Cycle::UranusOpposition => 180.0,
+            _ => 0.0,
+        }
+    }
+
+    /// Sampling step in days; short enough not to skip a station
+    fn step(&self) -> f64 {
+        match self {
+            Cycle::SolarReturn => 1.0,
+            _ => 5.0,
+        }
+    }
+}
+
+/// One exact return with its chart and overlay on the natal chart
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanetReturn {
+    pub cycle: Cycle,
+    pub at: DateTime<Utc>,
+    /// Reached while the body moves backward (a retrograde pass)
+    pub retrograde: bool,
+    /// Chart cast for the return instant
+    pub chart: Chart,
+    pub analysis: ChartAnalysis,
+    /// Channels the return chart's personality completes in the natal chart
+    pub overlay: Vec<CompletedChannel>,
+}
+
+/// Default search range: the year after `now` for the solar return, else
+/// from shortly before the first expected occurrence to well after it
+pub fn default_range(cycle: Cycle, birth: DateTime<Utc>, now: DateTime<Utc>) -> (DateTime<Utc>, DateTime<Utc>) {
+    let days = |years: f64| Duration::days((years * 365.25) as i64);
+    match cycle {
+        Cycle::SolarReturn => (now, now + days(1.0)),
+        _ => {
+            let age = cycle.first_age();
+            (birth + days(age * 0.85), birth + days(age * 1.15))
+        }
+    }
+}
+
+/// Find every exact return between `from` and `to` and cast its chart with
+/// the natal chart's node model
+pub fn returns(cycle: Cycle, birth: DateTime<Utc>, natal: &Chart, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PlanetReturn>> {
+    let natal_defined = analyze_gates(&natal.gates()).defined_channels;
+    let options = ChartOptions { node_model: natal.node_model, ..ChartOptions::default() };
+    return_instants(cycle, birth, from, to)?.into_iter()
+        .map(|(at, retrograde)| {
+            let chart = calculate_chart_at_with(at, &options)?;
+            let analysis = analyze(&chart)?;
+            let overlay = overlay_transits(&natal.gates(), &chart.personality, &natal_defined);
+            Ok(PlanetReturn { cycle, at, retrograde, chart, analysis, overlay })
+        })
+        .collect()
+}
+
+/// Exact instants the cycle completes between `from` and `to`, each with
+/// whether the body was retrograde
+pub fn return_instants(cycle: Cycle, birth: DateTime<Utc>, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<(DateTime<Utc>, bool)>> {
+    if to <= from {
+        return Err(HdError::InvalidRange(format!("{} .. {}", from.to_rfc3339(), to.to_rfc3339())));
+    }
+    let body = cycle.body();
+    let target = planet_longitude(body, julian_day(birth)?)? + cycle.angle();
+    // Signed distance from the target in (-180, 180]
+    let offset = |jd: f64| -> Result<f64> {
+        let d = (planet_longitude(body, jd)? - target).rem_euclid(360.0);
+        Ok(if d > 180.0 { d - 360.0 } else { d })
+    };
+
+    let (start, end) = (julian_day(from)?, julian_day(to)?);
+    let mut hits = Vec::new();
+    let (mut t0, mut d0) = (start, offset(start)?);
+    while t0 < end {
+        let t1 = (t0 + cycle.step()).min(end);
+        let d1 = offset(t1)?;
+        // A sign change far from the target is the wrap at ±180°, not a return
+        if (d0 < 0.0) != (d1 < 0.0) && d0.abs() < 90.0 && d1.abs() < 90.0 {
+            let (mut lo, mut hi) = (t0, t1);
+            while hi - lo > PRECISION {
+                let mid = (lo + hi) / 2.0;
+                if (offset(mid)? < 0.0) == (d0 < 0.0) { lo = mid } else { hi = mid }
+            }
+            hits.push((instant_from_jd(hi), d0 > 0.0));
+        }
+        (t0, d0) = (t1, d1);
+    }
+    Ok(hits)
+}
@@ -0,0 +1,259 @@
+//! Reverse search: birth moments whose chart matches a set of constraints.
+//!
+//! Activations only change at line boundaries, so instead of stepping
+//! minute by minute the range is cut at the ingresses of the bodies the
+//! query depends on, found by bracketing as in [`crate::ingress`]. Design
+//! ingresses are mapped back to the birth moments that put them there. Each
+//! piece between two cuts is then checked once.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::chart::{birth_jd_for_design, body_longitude, body_names, chart_at_jd, find_design_jd,
+    instant_from_jd, julian_day, ChartOptions, NodeModel};
+use crate::error::{HdError, Result};
+use crate::gates::{all_channels, longitude_to_subdivision};
+use crate::ingress::body_timeline;
+use crate::types::{analyze, HdType};
+
+/// Personality (conscious) or Design (unconscious) activation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Side {
+    Personality,
+    Design,
+}
+
+impl Side {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Side::Personality => "Personality",
+            Side::Design => "Design",
+        }
+    }
+}
+
+/// A body on one side in a gate, and optionally a line
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivationConstraint {
+    pub planet: &'static str,
+    pub side: Side,
+    pub gate: u8,
+    pub line: Option<u8>,
+}
+
+impl ActivationConstraint {
+    /// Parse `[design|personality] PLANET GATE[.LINE]`, e.g. `Sun 13`,
+    /// `design Moon 41.3` or `North Node 2`; the side defaults to Personality
+    pub fn parse(s: &str) -> Result<ActivationConstraint> {
+        let err = || HdError::InvalidQuery(s.to_string());
+        let mut words: Vec<&str> = s.split_whitespace().collect();
+        let side = match words.first().map(|w| w.to_ascii_lowercase()).as_deref() {
+            Some("design") => Side::Design,
+            Some("personality") => Side::Personality,
+            _ => {
+                words.insert(0, "personality");
+                Side::Personality
+            }
+        };
+        let (position, planet) = match words.as_slice() {
+            [_, planet @ .., position] if !planet.is_empty() => (*position, planet.join(" ")),
+            _ => return Err(err()),
+        };
+        let planet = body_names().find(|name| name.eq_ignore_ascii_case(&planet)).ok_or_else(err)?;
+        let (gate, line) = match position.split_once('.') {
+            Some((gate, line)) => (gate, Some(line.parse::<u8>().map_err(|_| err())?)),
+            None => (position, None),
+        };
+        let gate: u8 = gate.parse().map_err(|_| err())?;
+        if !(1..=64).contains(&gate) || line.is_some_and(|l| !(1..=6).contains(&l)) {
+            return Err(err());
+        }
+        Ok(ActivationConstraint { planet, side, gate, line })
+    }
+
+    fn index(&self) -> usize {
+        body_names().position(|name| name == self.planet).expect("planet from body_names")
+    }
+}
+
+/// Parse a channel as `GATE-GATE`, e.g. `1-8` or `8-1`
+pub fn parse_channel(s: &str) -> Result<(u8, u8)> {
+    let err = || HdError::InvalidQuery(s.to_string());
+    let (a, b) = s.trim().split_once('-').ok_or_else(err)?;
+    let (a, b): (u8, u8) = (a.trim().parse().map_err(|_| err())?, b.trim().parse().map_err(|_| err())?);
+    let pair = (a.min(b), a.max(b));
+    all_channels().iter()
+        .any(|ch| (ch.gate1.min(ch.gate2), ch.gate1.max(ch.gate2)) == pair)
+        .then_some(pair)
+        .ok_or_else(err)
+}
+
+/// Parse a profile as `LINE/LINE`, e.g. `1/3`
+pub fn parse_profile(s: &str) -> Result<(u8, u8)> {
+    let err = || HdError::InvalidQuery(s.to_string());
+    let (p, d) = s.trim().split_once('/').ok_or_else(err)?;
+    let (p, d): (u8, u8) = (p.trim().parse().map_err(|_| err())?, d.trim().parse().map_err(|_| err())?);
+    if !(1..=6).contains(&p) || !(1..=6).contains(&d) {
+        return Err(err());
+    }
+    Ok((p, d))
+}
+
+/// Constraints a matching chart must satisfy, all at once
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    pub activations: Vec<ActivationConstraint>,
+    pub hd_type: Option<HdType>,
+    /// Authority name prefix, ignoring case: "emotional", "sacral", ...
+    pub authority: Option<String>,
+    /// Channels that must all be defined, as (lower gate, higher gate)
+    pub channels: Vec<(u8, u8)>,
+    pub profile: Option<(u8, u8)>,
+}
+
+impl Query {
+    /// Type, authority and channels depend on every gate; the profile on
+    /// the Sun lines only
+    fn needs_gates(&self) -> bool {
+        self.hd_type.is_some() || self.authority.is_some() || !self.channels.is_empty()
+    }
+
+    /// Bodies whose changes can change the outcome of the chart-level
+    /// constraints: (index, side, lines too)
+    fn watched(&self) -> Vec<(usize, Side, bool)> {
+        let mut watched = Vec::new();
+        for index in 0..body_names().count() {
+            let lines = index == 0 && self.profile.is_some();
+            if lines || self.needs_gates() {
+                watched.push((index, Side::Personality, lines));
+                watched.push((index, Side::Design, lines));
+            }
+        }
+        watched
+    }
+}
+
+/// Bodies from the slowest mean motion to the fastest; the nodes move
+/// slower than Jupiter, and Sun and Earth slower than Venus and Mercury
+const SPEED_ORDER: [&str; 13] = [
+    "Pluto", "Neptune", "Uranus", "Saturn", "North Node", "South Node", "Jupiter",
+    "Mars", "Sun", "Earth", "Venus", "Mercury", "Moon",
+];
+
+/// Interval of birth moments that all match the query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchMatch {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+/// Every interval between `from` and `to` in which a chart cast for the
+/// birth moment satisfies the query, in chronological order. An empty query
+/// matches the whole range.
+///
+/// Activation constraints narrow the candidate intervals one at a time,
+/// slow bodies first, so fast bodies are only followed where the slow ones
+/// already match. The remaining constraints are checked last.
+pub fn search(query: &Query, from: DateTime<Utc>, to: DateTime<Utc>, node: NodeModel) -> Result<Vec<SearchMatch>> {
+    if to <= from {
+        return Err(HdError::InvalidRange(format!("{} .. {}", from.to_rfc3339(), to.to_rfc3339())));
+    }
+    let mut candidates = vec![(julian_day(from)?, julian_day(to)?)];
+
+    let mut activations: Vec<&ActivationConstraint> = query.activations.iter().collect();
+    activations.sort_by_key(|c| SPEED_ORDER.iter().position(|&p| p == c.planet));
+    for c in activations {
+        candidates = refine(&candidates, &[(c.index(), c.side, c.line.is_some())], node, |jd| {
+            activation_matches(c, jd, node)
+        })?;
+    }
+    if query.profile.is_some() || query.needs_gates() {
+        let options = ChartOptions { node_model: node, ..ChartOptions::default() };
+        candidates = refine(&candidates, &query.watched(), node, |jd| chart_matches(query, jd, &options))?;
+    }
+
+    let mut matches: Vec<SearchMatch> = candidates.into_iter()
+        .map(|(begin, end)| SearchMatch { from: instant_from_jd(begin), to: instant_from_jd(end) })
+        .collect();
+    if let Some(first) = matches.first_mut() {
+        first.from = first.from.max(from);
+    }
+    if let Some(last) = matches.last_mut() {
+        last.to = last.to.min(to);
+    }
+    Ok(matches)
+}
+
+/// Cut each interval at the ingresses of the watched bodies and keep the
+/// pieces that pass `check` at their midpoint, merging neighbours
+fn refine(
+    intervals: &[(f64, f64)],
+    watched: &[(usize, Side, bool)],
+    node: NodeModel,
+    check: impl Fn(f64) -> Result<bool>,
+) -> Result<Vec<(f64, f64)>> {
+    let mut kept: Vec<(f64, f64)> = Vec::new();
+    for &(start, end) in intervals {
+        let cuts = cuts(start, end, watched, node)?;
+        for piece in cuts.windows(2) {
+            if !check((piece[0] + piece[1]) / 2.0)? {
+                continue;
+            }
+            match kept.last_mut() {
+                Some(last) if last.1 == piece[0] => last.1 = piece[1],
+                _ => kept.push((piece[0], piece[1])),
+            }
+        }
+    }
+    Ok(kept)
+}
+
+/// Birth moments in [start, end] at which a watched body changes gate, or
+/// line when asked; Design ingresses are mapped back to the birth moment
+fn cuts(start: f64, end: f64, watched: &[(usize, Side, bool)], node: NodeModel) -> Result<Vec<f64>> {
+    let mut design_range = None;
+    let mut cuts = vec![start, end];
+    for &(index, side, lines) in watched {
+        let (t0, t1) = match side {
+            Side::Personality => (start, end),
+            Side::Design => *match &mut design_range {
+                Some(range) => range,
+                slot => slot.insert((find_design_jd(start)?, find_design_jd(end)?)),
+            },
+        };
+        for ingress in body_timeline(index, t0, t1, node, !lines)? {
+            let jd = julian_day(ingress.at)?;
+            cuts.push(match side {
+                Side::Personality => jd,
+                Side::Design => birth_jd_for_design(jd)?,
+            });
+        }
+    }
+    cuts.retain(|&jd| (start..=end).contains(&jd));
+    cuts.sort_by(f64::total_cmp);
+    cuts.dedup();
+    Ok(cuts)
+}
+
+fn activation_matches(c: &ActivationConstraint, jd: f64, node: NodeModel) -> Result<bool> {
+    let at = match c.side {
+        Side::Personality => jd,
+        Side::Design => find_design_jd(jd)?,
+    };
+    let sub = longitude_to_subdivision(body_longitude(c.index(), at, node)?);
+    Ok(sub.gate == c.gate && c.line.is_none_or(|line| line == sub.line))
+}
+
+/// Check the type, authority, channels and profile of the chart at one
+/// birth moment
+fn chart_matches(query: &Query, jd: f64, options: &ChartOptions) -> Result<bool> {
+    let analysis = analyze(&chart_at_jd(jd, options)?)?;
+    let authority_matches = |prefix: &str| analysis.authority.to_lowercase().starts_with(&prefix.trim().to_lowercase());
+    Ok(query.hd_type.is_none_or(|t| t.name() == analysis.hd_type.name())
+        && query.authority.as_deref().is_none_or(authority_matches)
+        && query.profile.is_none_or(|p| p == analysis.profile)
+        && query.channels.iter().all(|&(a, b)| analysis.defined_channels.iter()
+            .any(|ch| (ch.gate1.min(ch.gate2), ch.gate1.max(ch.gate2)) == (a, b))))
+}
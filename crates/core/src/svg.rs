@@ -1,7 +1,10 @@
 use std::collections::HashSet;
 use crate::chart::Chart;
+use crate::composite::Composite;
 use crate::gates::{Center, all_channels};
+use crate::penta::Penta;
 use crate::types::ChartAnalysis;
+use crate::variables::{Arrow, Variable};
 
 /// Base SVG template from hdkit (MIT license, Jonah Dempcy 2023)
 const TEMPLATE: &str = include_str!("../assets/bodygraph-blank.svg");
@@ -9,8 +12,6 @@ const TEMPLATE: &str = include_str!("../assets/bodygraph-blank.svg");
 // Colors matching traditional HD bodygraph
 const COLOR_PERSONALITY: &str = "#333333";    // black (conscious)
 const COLOR_DESIGN: &str = "#A44344";         // red (unconscious)
-const COLOR_BOTH_P: &str = "#333333";         // split: personality half
-const COLOR_BOTH_D: &str = "#A44344";         // split: design half
 const COLOR_TRANSIT: &str = "#44aa55";        // green (transit activation)
 const COLOR_UNDEFINED: &str = "#e0ddd8";       // light gray (inactive gate/channel)
 const COLOR_GATE_TEXT_ACTIVE: &str = "#343434"; // dark text for active gates
@@ -56,10 +57,35 @@ pub fn generate_svg(
 ) -> String {
     let p_gates: HashSet<u8> = chart.personality.iter().map(|a| a.gate).collect();
     let d_gates: HashSet<u8> = chart.design.iter().map(|a| a.gate).collect();
-    let all_natal: HashSet<u8> = p_gates.union(&d_gates).copied().collect();
     let empty = HashSet::new();
     let t_gates = transit_gates.unwrap_or(&empty);
 
+    let svg = color_bodygraph(&p_gates, &d_gates, t_gates, &analysis.defined_centers);
+
+    // 4. Variable arrows beside the Head: design on the left, personality on the right
+    let svg = insert_variables(&svg, analysis);
+
+    // 5. Opt-in extra bodies listed in the bottom corners, same sides as the arrows
+    insert_extras(&svg, chart)
+}
+
+/// Generate a two-color composite bodygraph: person A's gates in the
+/// personality color, person B's in the design color. Gates both people
+/// share take person A's color. No variable arrows are drawn.
+pub fn generate_composite_svg(a: &Chart, b: &Chart, composite: &Composite) -> String {
+    color_bodygraph(&a.gates(), &b.gates(), &HashSet::new(), &composite.combined.defined_centers)
+}
+
+/// Steps 1-3 of the bodygraph: gates in the primary (black) or secondary
+/// (red) color, transit-only gates in green, and the defined centers
+fn color_bodygraph(
+    p_gates: &HashSet<u8>,
+    d_gates: &HashSet<u8>,
+    t_gates: &HashSet<u8>,
+    defined_centers: &HashSet<Center>,
+) -> String {
+    let all_natal: HashSet<u8> = p_gates.union(d_gates).copied().collect();
+
     let mut svg = TEMPLATE.to_string();
 
     // 1. Color all gate channels — active ones in personality/design colors, inactive in gray
@@ -68,9 +94,8 @@ pub fn generate_svg(
         let in_d = d_gates.contains(&gate_num);
         let in_t = t_gates.contains(&gate_num);
 
-        let color = if in_p && in_d {
-            COLOR_PERSONALITY
-        } else if in_p {
+        // Personality wins when a gate is activated on both sides
+        let color = if in_p {
             COLOR_PERSONALITY
         } else if in_d {
             COLOR_DESIGN
@@ -115,7 +140,7 @@ pub fn generate_svg(
     }
 
     for &center in &all_centers {
-        if analysis.defined_centers.contains(&center) {
+        if defined_centers.contains(&center) {
             let color = center_color(center);
             let id = center_svg_id(center);
             svg = replace_center_fill(&svg, id, color);
@@ -150,6 +175,155 @@ pub fn generate_svg(
     svg
 }
 
+// Penta diagram layout: gate positions (x, y) for Throat, G and Sacral rows
+const PENTA_WIDTH: f64 = 400.0;
+const PENTA_HEIGHT: f64 = 520.0;
+const PENTA_GATES: [(u8, f64, f64); 12] = [
+    (31, 165.0, 132.0), (8, 200.0, 132.0), (33, 235.0, 132.0),
+    (7, 172.0, 222.0), (1, 200.0, 204.0), (13, 228.0, 222.0),
+    (15, 172.0, 298.0), (2, 200.0, 316.0), (46, 228.0, 298.0),
+    (5, 165.0, 388.0), (14, 200.0, 388.0), (29, 235.0, 388.0),
+];
+
+/// Generate a Penta diagram: the Throat, G and Sacral centers with the six
+/// Penta channels. Filled gates are dark, missing gates outlined, and a
+/// channel is drawn in full when the group covers both of its gates.
+pub fn generate_penta_svg(penta: &Penta) -> String {
+    let pos = |gate: u8| PENTA_GATES.iter()
+        .find(|&&(g, _, _)| g == gate)
+        .map_or((0.0, 0.0), |&(_, x, y)| (x, y));
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\">\n",
+        w = PENTA_WIDTH, h = PENTA_HEIGHT);
+
+    // Centers
+    svg.push_str("  <g id=\"Centers\">\n");
+    svg.push_str(&format!(
+        "    <rect id=\"Throat\" x=\"130\" y=\"40\" width=\"140\" height=\"110\" rx=\"8\" fill=\"{}\" fill-opacity=\"0.25\" stroke=\"#999\"/>\n",
+        center_color(Center::Throat)));
+    svg.push_str(&format!(
+        "    <polygon id=\"G\" points=\"200,180 290,260 200,340 110,260\" fill=\"{}\" stroke=\"#999\"/>\n",
+        center_color(Center::G)));
+    svg.push_str(&format!(
+        "    <rect id=\"Sacral\" x=\"130\" y=\"370\" width=\"140\" height=\"110\" rx=\"8\" fill=\"{}\" fill-opacity=\"0.25\" stroke=\"#999\"/>\n",
+        center_color(Center::Sacral)));
+    svg.push_str("  </g>\n");
+
+    // Channels
+    svg.push_str("  <g id=\"Channels\">\n");
+    for role in &penta.roles {
+        let (x1, y1) = pos(role.gate1);
+        let (x2, y2) = pos(role.gate2);
+        let (color, width) = if role.covered { (COLOR_PERSONALITY, 5) } else { (COLOR_UNDEFINED, 3) };
+        svg.push_str(&format!(
+            "    <line id=\"Channel{}-{}\" x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"{}\" stroke-width=\"{}\"><title>{} ({}-{})</title></line>\n",
+            role.gate1, role.gate2, x1, y1, x2, y2, color, width, role.role, role.gate1, role.gate2));
+    }
+    svg.push_str("  </g>\n");
+
+    // Gates
+    svg.push_str("  <g id=\"Gates\">\n");
+    for gate in &penta.gates {
+        let (x, y) = pos(gate.gate);
+        let (fill, text) = if gate.is_filled() { (COLOR_PERSONALITY, "#ffffff") } else { ("#ffffff", "#999999") };
+        let members: Vec<String> = gate.members.iter().map(|i| (i + 1).to_string()).collect();
+        let title = if members.is_empty() {
+            format!("Gate {} {}: missing", gate.gate, gate.keynote)
+        } else {
+            format!("Gate {} {}: member {}", gate.gate, gate.keynote, members.join(", "))
+        };
+        svg.push_str(&format!(
+            "    <g id=\"Gate{g}\"><title>{t}</title><circle cx=\"{x}\" cy=\"{y}\" r=\"12\" fill=\"{f}\" stroke=\"#999\"/><text x=\"{x}\" y=\"{ty}\" font-family=\"sans-serif\" font-size=\"11\" text-anchor=\"middle\" fill=\"{c}\">{g}</text></g>\n",
+            g = gate.gate, t = title, x = x, y = y, ty = y + 4.0, f = fill, c = text));
+    }
+    svg.push_str("  </g>\n");
+
+    svg.push_str(&format!(
+        "  <text x=\"{}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"13\" text-anchor=\"middle\" fill=\"#343434\">Penta of {}: {} of 12 gates filled</text>\n",
+        PENTA_WIDTH / 2.0, PENTA_HEIGHT - 15.0, penta.size, penta.filled.len()));
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Arrow anchor points beside the Head center (x, y)
+const ARROW_TOP_LEFT: (f64, f64) = (270.0, 60.0);
+const ARROW_BOTTOM_LEFT: (f64, f64) = (270.0, 120.0);
+const ARROW_TOP_RIGHT: (f64, f64) = (580.0, 60.0);
+const ARROW_BOTTOM_RIGHT: (f64, f64) = (580.0, 120.0);
+
+/// Append the four variable arrows as a `Variables` group before `</svg>`
+fn insert_variables(svg: &str, analysis: &ChartAnalysis) -> String {
+    let v = &analysis.variables;
+    let arrows = [
+        ("Determination", &v.determination, ARROW_TOP_LEFT, COLOR_DESIGN),
+        ("Environment", &v.environment, ARROW_BOTTOM_LEFT, COLOR_DESIGN),
+        ("Motivation", &v.motivation, ARROW_TOP_RIGHT, COLOR_PERSONALITY),
+        ("Perspective", &v.perspective, ARROW_BOTTOM_RIGHT, COLOR_PERSONALITY),
+    ];
+    let mut group = String::from("  <g id=\"Variables\">\n");
+    for (id, var, (x, y), color) in arrows {
+        group.push_str(&arrow_element(id, var, x, y, color));
+    }
+    group.push_str("  </g>\n");
+
+    match svg.rfind("</svg>") {
+        Some(pos) => format!("{}{}{}", &svg[..pos], group, &svg[pos..]),
+        None => svg.to_string(),
+    }
+}
+
+// Extra body panels beside the Root center: first row baseline and row height
+const EXTRAS_LEFT: (f64, f64) = (30.0, 1230.0);
+const EXTRAS_RIGHT: (f64, f64) = (821.0, 1230.0);
+const EXTRAS_ROW: f64 = 22.0;
+
+/// Append an `Extras` group listing extra bodies, design at the bottom left
+/// and personality at the bottom right; unchanged when there are none
+fn insert_extras(svg: &str, chart: &Chart) -> String {
+    if chart.personality_extras.is_empty() && chart.design_extras.is_empty() {
+        return svg.to_string();
+    }
+    let panels = [
+        ("Design", &chart.design_extras, EXTRAS_LEFT, "start", COLOR_DESIGN),
+        ("Personality", &chart.personality_extras, EXTRAS_RIGHT, "end", COLOR_PERSONALITY),
+    ];
+    let mut group = String::from("  <g id=\"Extras\">\n");
+    for (side, activations, (x, y), anchor, color) in panels {
+        for (i, a) in activations.iter().enumerate() {
+            group.push_str(&format!(
+                "    <text id=\"Extra{}{}\" x=\"{}\" y=\"{:.1}\" font-family=\"sans-serif\" font-size=\"16\" text-anchor=\"{}\" fill=\"{}\">{} {}.{}</text>\n",
+                side, a.planet,
+                x, y + i as f64 * EXTRAS_ROW, anchor, color, a.planet, a.gate, a.line,
+            ));
+        }
+    }
+    group.push_str("  </g>\n");
+
+    match svg.rfind("</svg>") {
+        Some(pos) => format!("{}{}{}", &svg[..pos], group, &svg[pos..]),
+        None => svg.to_string(),
+    }
+}
+
+/// A horizontal arrow centered on (x, y) pointing in the variable's direction
+fn arrow_element(id: &str, var: &Variable, x: f64, y: f64, color: &str) -> String {
+    // Right-pointing outline relative to the center; mirrored for left
+    let outline = [(-25.0, -4.0), (10.0, -4.0), (10.0, -11.0), (25.0, 0.0),
+        (10.0, 11.0), (10.0, 4.0), (-25.0, 4.0)];
+    let dir = match var.arrow {
+        Arrow::Left => -1.0,
+        Arrow::Right => 1.0,
+    };
+    let points: Vec<String> = outline.iter()
+        .map(|(dx, dy)| format!("{:.1},{:.1}", x + dir * dx, y + dy))
+        .collect();
+    format!(
+        "    <polygon id=\"Arrow{}\" points=\"{}\" fill=\"{}\"><title>{}: {} {} (color {}, tone {})</title></polygon>\n",
+        id, points.join(" "), color, id, var.arrow.name(), var.name, var.color, var.tone,
+    )
+}
+
 /// Replace the `fill` attribute of an element found by its `id` attribute.
 /// Handles both `<path id="GateXX" ... fill="#fff" ...>` patterns.
 fn replace_element_fill(svg: &str, element_id: &str, new_fill: &str) -> String {
@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use crate::chart::{body_names, calculate_transit_at_with, julian_day, Activation, NodeModel};
+use crate::error::{HdError, Result};
+use crate::gates::{all_channels, Center};
+use crate::ingress::body_timeline;
+use crate::types::DefinedChannel;
+
+/// Transit overlay result
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitOverlay {
+    /// Instant the transit positions are calculated for
+    pub at: DateTime<Utc>,
+    /// Transiting planet activations
+    pub transit_positions: Vec<Activation>,
+    /// Channels not defined natally but completed while the transit lasts
+    pub completed_channels: Vec<CompletedChannel>,
+}
+
+/// A channel completed by transit activating a missing gate
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletedChannel {
+    pub gate1: u8,
+    pub gate2: u8,
+    pub name: &'static str,
+    pub center1: Center,
+    pub center2: Center,
+    /// Natal gate of the channel, or 0 when both gates come from transit
+    pub natal_gate: u8,
+    /// Transit gate completing the channel, or 0 when both gates come from transit
+    pub transit_gate: u8,
+}
+
+/// Calculate which channels are temporarily completed by current transits
+pub fn overlay_transits(
+    natal_gates: &HashSet<u8>,
+    transit_positions: &[Activation],
+    already_defined: &[DefinedChannel],
+) -> Vec<CompletedChannel> {
+    let transit_gates: HashSet<u8> = transit_positions.iter().map(|a| a.gate).collect();
+    let defined_pairs: HashSet<(u8, u8)> = already_defined.iter()
+        .map(|ch| (ch.gate1, ch.gate2))
+        .collect();
+
+    let channels = all_channels();
+    let mut completed = Vec::new();
+
+    for ch in &channels {
+        let pair = (ch.gate1, ch.gate2);
+        if defined_pairs.contains(&pair) {
+            continue; // Already defined in natal chart
+        }
+
+        // Check if one gate is natal and the other is transit (or vice versa)
+        let g1_natal = natal_gates.contains(&ch.gate1);
+        let g2_natal = natal_gates.contains(&ch.gate2);
+        let g1_transit = transit_gates.contains(&ch.gate1);
+        let g2_transit = transit_gates.contains(&ch.gate2);
+
+        if (g1_natal && g2_transit) || (g2_natal && g1_transit) {
+            let (natal_g, transit_g) = if g1_natal && g2_transit {
+                (ch.gate1, ch.gate2)
+            } else {
+                (ch.gate2, ch.gate1)
+            };
+            completed.push(CompletedChannel {
+                gate1: ch.gate1,
+                gate2: ch.gate2,
+                name: ch.name,
+                center1: ch.center1,
+                center2: ch.center2,
+                natal_gate: natal_g,
+                transit_gate: transit_g,
+            });
+        }
+
+        // Also check if both gates come from transit (pure transit channel)
+        if g1_transit && g2_transit && !g1_natal && !g2_natal {
+            completed.push(CompletedChannel {
+                gate1: ch.gate1,
+                gate2: ch.gate2,
+                name: ch.name,
+                center1: ch.center1,
+                center2: ch.center2,
+                natal_gate: 0, // pure transit
+                transit_gate: 0,
+            });
+        }
+    }
+
+    completed
+}
+
+/// Interval during which one transiting body completes a natal channel
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransitWindow {
+    /// Ingress into the transit gate, or the start of the range
+    pub start: DateTime<Utc>,
+    /// Egress from the transit gate, or the end of the range
+    pub end: DateTime<Utc>,
+    pub planet: &'static str,
+    #[serde(flatten)]
+    pub channel: CompletedChannel,
+    /// Natally open centers the channel defines while the window lasts
+    pub opened_centers: Vec<Center>,
+}
+
+/// Forecast the windows in which a transit completes a natal channel.
+///
+/// Each body's stay in a gate is one window; stays are clipped to the range.
+/// Channels completed only by two transiting bodies together are not
+/// included, as they do not involve the natal chart.
+pub fn forecast(
+    natal_gates: &HashSet<u8>,
+    already_defined: &[DefinedChannel],
+    defined_centers: &HashSet<Center>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    node: NodeModel,
+) -> Result<Vec<TransitWindow>> {
+    if to <= from {
+        return Err(HdError::InvalidRange(format!("{} .. {}", from.to_rfc3339(), to.to_rfc3339())));
+    }
+    let (start_jd, end_jd) = (julian_day(from)?, julian_day(to)?);
+    let defined_pairs: HashSet<(u8, u8)> = already_defined.iter()
+        .map(|ch| (ch.gate1, ch.gate2))
+        .collect();
+    let channels = all_channels();
+
+    let mut windows = Vec::new();
+    for start in calculate_transit_at_with(from, node)? {
+        // Gate stays of this body: the gate at `from`, then each gate ingress
+        let index = body_names().position(|name| name == start.planet).expect("transit bodies are HD bodies");
+        let mut stays = vec![(from, start.gate)];
+        stays.extend(body_timeline(index, start_jd, end_jd, node, true)?.into_iter().map(|i| (i.at, i.gate)));
+
+        for (n, &(begin, gate)) in stays.iter().enumerate() {
+            let end = stays.get(n + 1).map_or(to, |&(next, _)| next);
+            for ch in channels.iter().filter(|ch| !defined_pairs.contains(&(ch.gate1, ch.gate2))) {
+                let natal_gate = if ch.gate1 == gate && natal_gates.contains(&ch.gate2) {
+                    ch.gate2
+                } else if ch.gate2 == gate && natal_gates.contains(&ch.gate1) {
+                    ch.gate1
+                } else {
+                    continue;
+                };
+                windows.push(TransitWindow {
+                    start: begin,
+                    end,
+                    planet: start.planet,
+                    channel: CompletedChannel {
+                        gate1: ch.gate1,
+                        gate2: ch.gate2,
+                        name: ch.name,
+                        center1: ch.center1,
+                        center2: ch.center2,
+                        natal_gate,
+                        transit_gate: gate,
+                    },
+                    opened_centers: [ch.center1, ch.center2].into_iter()
+                        .filter(|c| !defined_centers.contains(c))
+                        .collect(),
+                });
+            }
+        }
+    }
+    windows.sort_by_key(|w| w.start);
+    Ok(windows)
+}
@@ -0,0 +1,464 @@
+use serde::{Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::chart::{Activation, Chart};
+use crate::crosses::{incarnation_cross, IncarnationCross};
+use crate::error::{HdError, Result};
+use crate::gates::{all_channels, gate_circuits, Center, Circuit, SubCircuit};
+use crate::variables::{variables, Variables};
+
+/// Human Design Type
+#[derive(Debug, Clone, Copy)]
+pub enum HdType {
+    Generator,
+    ManifestingGenerator,
+    Manifestor,
+    Projector,
+    Reflector,
+}
+
+/// Serializes as the display name, e.g. "Manifesting Generator"
+impl Serialize for HdType {
+    fn serialize<S: Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.name())
+    }
+}
+
+impl HdType {
+    pub const ALL: [HdType; 5] = [
+        HdType::Generator,
+        HdType::ManifestingGenerator,
+        HdType::Manifestor,
+        HdType::Projector,
+        HdType::Reflector,
+    ];
+
+    /// Type of a name, ignoring case, spaces and hyphens ("manifesting-generator")
+    pub fn from_name(name: &str) -> Option<HdType> {
+        let key = |s: &str| s.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+        HdType::ALL.into_iter().find(|t| key(t.name()) == key(name))
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            HdType::Generator => "Generator",
+            HdType::ManifestingGenerator => "Manifesting Generator",
+            HdType::Manifestor => "Manifestor",
+            HdType::Projector => "Projector",
+            HdType::Reflector => "Reflector",
+        }
+    }
+
+    pub fn strategy(&self) -> &'static str {
+        match self {
+            HdType::Generator | HdType::ManifestingGenerator => "To Respond",
+            HdType::Manifestor => "To Inform",
+            HdType::Projector => "Wait for the Invitation",
+            HdType::Reflector => "Wait a Lunar Cycle",
+        }
+    }
+
+    pub fn signature(&self) -> &'static str {
+        match self {
+            HdType::Generator | HdType::ManifestingGenerator => "Satisfaction",
+            HdType::Manifestor => "Peace",
+            HdType::Projector => "Success",
+            HdType::Reflector => "Surprise",
+        }
+    }
+
+    pub fn not_self(&self) -> &'static str {
+        match self {
+            HdType::Generator => "Frustration",
+            HdType::ManifestingGenerator => "Frustration & Anger",
+            HdType::Manifestor => "Anger",
+            HdType::Projector => "Bitterness",
+            HdType::Reflector => "Disappointment",
+        }
+    }
+}
+
+/// How the defined centers hang together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Definition {
+    None,
+    Single,
+    Split,
+    TripleSplit,
+    QuadrupleSplit,
+}
+
+impl Definition {
+    pub fn from_components(count: usize) -> Definition {
+        match count {
+            0 => Definition::None,
+            1 => Definition::Single,
+            2 => Definition::Split,
+            3 => Definition::TripleSplit,
+            _ => Definition::QuadrupleSplit,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Definition::None => "No Definition",
+            Definition::Single => "Single Definition",
+            Definition::Split => "Split Definition",
+            Definition::TripleSplit => "Triple Split Definition",
+            Definition::QuadrupleSplit => "Quadruple Split Definition",
+        }
+    }
+}
+
+/// Serializes as the display name, e.g. "Triple Split Definition"
+impl Serialize for Definition {
+    fn serialize<S: Serializer>(&self, s: S) -> std::result::Result<S::Ok, S::Error> {
+        s.serialize_str(self.name())
+    }
+}
+
+/// An inactive gate that would connect two separate areas of definition
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BridgingGate {
+    pub gate: u8,
+    /// Already active gate at the other end of the channel
+    pub hanging_gate: u8,
+    pub channel_name: &'static str,
+    /// Centers the channel would join, one from each area
+    pub joins: (Center, Center),
+}
+
+/// Defined channel info
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DefinedChannel {
+    pub gate1: u8,
+    pub gate2: u8,
+    pub name: &'static str,
+    pub center1: Center,
+    pub center2: Center,
+    pub circuit: Circuit,
+    pub sub_circuit: Option<SubCircuit>,
+}
+
+/// Number of defined channels per circuit group and sub-circuit
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CircuitryBalance {
+    pub circuits: BTreeMap<Circuit, usize>,
+    pub sub_circuits: BTreeMap<SubCircuit, usize>,
+    /// Circuit groups with the most defined channels (several on a tie,
+    /// empty without definition)
+    pub dominant: Vec<Circuit>,
+    /// Active gates outside the defined channels, per circuit group of the
+    /// channels they could complete
+    pub hanging_gates: BTreeMap<Circuit, usize>,
+}
+
+impl CircuitryBalance {
+    pub fn new(all_gates: &HashSet<u8>, channels: &[DefinedChannel]) -> Self {
+        let circuits: BTreeMap<Circuit, usize> = Circuit::ALL.iter()
+            .map(|&c| (c, channels.iter().filter(|ch| ch.circuit == c).count()))
+            .collect();
+        let sub_circuits = SubCircuit::ALL.iter()
+            .map(|&sc| (sc, channels.iter().filter(|ch| ch.sub_circuit == Some(sc)).count()))
+            .collect();
+        let max = circuits.values().copied().max().unwrap_or(0);
+        let dominant = circuits.iter()
+            .filter(|&(_, &n)| n > 0 && n == max)
+            .map(|(&c, _)| c)
+            .collect();
+        let mut hanging_gates: BTreeMap<Circuit, usize> = Circuit::ALL.iter().map(|&c| (c, 0)).collect();
+        for &gate in all_gates {
+            if !channels.iter().any(|ch| ch.gate1 == gate || ch.gate2 == gate) {
+                for circuit in gate_circuits(gate) {
+                    *hanging_gates.entry(circuit).or_default() += 1;
+                }
+            }
+        }
+        CircuitryBalance { circuits, sub_circuits, dominant, hanging_gates }
+    }
+}
+
+/// Analysis result
+///
+/// Serializes to the web app's `ChartAnalysis` shape (camelCase, `type`).
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAnalysis {
+    #[serde(rename = "type")]
+    pub hd_type: HdType,
+    pub authority: &'static str,
+    /// (personality Sun line, design Sun line)
+    pub profile: (u8, u8),
+    pub profile_name: &'static str,
+    pub incarnation_cross: (u8, u8, u8, u8), // p_sun, p_earth, d_sun, d_earth
+    /// Named cross with angle and quarter
+    pub cross: Option<IncarnationCross>,
+    pub defined_channels: Vec<DefinedChannel>,
+    #[serde(serialize_with = "serialize_center_set")]
+    pub defined_centers: HashSet<Center>,
+    pub open_centers: Vec<Center>,
+    /// The four arrows (Determination, Environment, Motivation, Perspective)
+    pub variables: Variables,
+    pub definition: Definition,
+    /// Connected areas of definition, each listed in bodygraph order
+    pub definition_groups: Vec<Vec<Center>>,
+    /// Single gates that would join two areas of a split definition
+    pub bridging_gates: Vec<BridgingGate>,
+    /// Defined channels per circuit group and sub-circuit
+    pub circuitry: CircuitryBalance,
+}
+
+/// Type, authority and definition derived from a set of active gates alone
+///
+/// This is the part of [`ChartAnalysis`] that does not depend on which
+/// planet activates a gate, so it also applies to merged gate sets such as
+/// composites.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GateAnalysis {
+    #[serde(rename = "type")]
+    pub hd_type: HdType,
+    pub authority: &'static str,
+    pub defined_channels: Vec<DefinedChannel>,
+    #[serde(serialize_with = "serialize_center_set")]
+    pub defined_centers: HashSet<Center>,
+    pub open_centers: Vec<Center>,
+    pub definition: Definition,
+    pub definition_groups: Vec<Vec<Center>>,
+    pub bridging_gates: Vec<BridgingGate>,
+    pub circuitry: CircuitryBalance,
+}
+
+/// Derive channels, centers, type, authority and definition from active gates
+pub fn analyze_gates(all_gates: &HashSet<u8>) -> GateAnalysis {
+    // Find defined channels
+    let channels = all_channels();
+    let mut defined_channels = Vec::new();
+    let mut defined_centers: HashSet<Center> = HashSet::new();
+
+    for ch in &channels {
+        if all_gates.contains(&ch.gate1) && all_gates.contains(&ch.gate2) {
+            defined_channels.push(DefinedChannel {
+                gate1: ch.gate1,
+                gate2: ch.gate2,
+                name: ch.name,
+                center1: ch.center1,
+                center2: ch.center2,
+                circuit: ch.circuit,
+                sub_circuit: ch.sub_circuit,
+            });
+            defined_centers.insert(ch.center1);
+            defined_centers.insert(ch.center2);
+        }
+    }
+
+    // Build center adjacency graph for motor-to-throat check
+    let mut adj: HashMap<Center, HashSet<Center>> = HashMap::new();
+    for ch in &defined_channels {
+        adj.entry(ch.center1).or_default().insert(ch.center2);
+        adj.entry(ch.center2).or_default().insert(ch.center1);
+    }
+
+    let definition_groups = connected_groups(&adj, &defined_centers);
+    let definition = Definition::from_components(definition_groups.len());
+    let bridging_gates = find_bridging_gates(all_gates, &definition_groups);
+
+    let has_sacral = defined_centers.contains(&Center::Sacral);
+    let motor_to_throat = is_connected_to_throat(&adj, &defined_centers);
+
+    // Determine type
+    let hd_type = if has_sacral {
+        if motor_to_throat {
+            HdType::ManifestingGenerator
+        } else {
+            HdType::Generator
+        }
+    } else if motor_to_throat {
+        HdType::Manifestor
+    } else if !defined_centers.is_empty() {
+        HdType::Projector
+    } else {
+        HdType::Reflector
+    };
+
+    // Authority
+    let authority = determine_authority(&defined_centers);
+
+    // Open centers
+    let open_centers: Vec<Center> = Center::ALL.iter()
+        .filter(|c| !defined_centers.contains(c))
+        .copied()
+        .collect();
+
+    GateAnalysis {
+        hd_type,
+        authority,
+        circuitry: CircuitryBalance::new(all_gates, &defined_channels),
+        defined_channels,
+        defined_centers,
+        open_centers,
+        definition,
+        definition_groups,
+        bridging_gates,
+    }
+}
+
+/// Derive type, authority, profile, cross, channels and centers from a chart
+pub fn analyze(chart: &Chart) -> Result<ChartAnalysis> {
+    let gates = analyze_gates(&chart.gates());
+
+    // Profile from Sun lines
+    let p_sun = find_activation(&chart.personality, "Sun")?;
+    let p_earth = find_activation(&chart.personality, "Earth")?;
+    let d_sun = find_activation(&chart.design, "Sun")?;
+    let d_earth = find_activation(&chart.design, "Earth")?;
+    let profile = (p_sun.line, d_sun.line);
+    let profile_name = match profile {
+        (1, 3) => "Investigator / Martyr",
+        (1, 4) => "Investigator / Opportunist",
+        (2, 4) => "Hermit / Opportunist",
+        (2, 5) => "Hermit / Heretic",
+        (3, 5) => "Martyr / Heretic",
+        (3, 6) => "Martyr / Role Model",
+        (4, 6) => "Opportunist / Role Model",
+        (4, 1) => "Opportunist / Investigator",
+        (5, 1) => "Heretic / Investigator",
+        (5, 2) => "Heretic / Hermit",
+        (6, 2) => "Role Model / Hermit",
+        (6, 3) => "Role Model / Martyr",
+        _ => "Unknown",
+    };
+
+    let cross_gates = (p_sun.gate, p_earth.gate, d_sun.gate, d_earth.gate);
+
+    Ok(ChartAnalysis {
+        hd_type: gates.hd_type,
+        authority: gates.authority,
+        profile,
+        profile_name,
+        incarnation_cross: cross_gates,
+        cross: incarnation_cross(cross_gates, profile),
+        defined_channels: gates.defined_channels,
+        defined_centers: gates.defined_centers,
+        open_centers: gates.open_centers,
+        variables: variables(chart)?,
+        definition: gates.definition,
+        definition_groups: gates.definition_groups,
+        bridging_gates: gates.bridging_gates,
+        circuitry: gates.circuitry,
+    })
+}
+
+/// Connected components of the defined-center graph, in bodygraph order
+fn connected_groups(adj: &HashMap<Center, HashSet<Center>>, defined_centers: &HashSet<Center>) -> Vec<Vec<Center>> {
+    let mut seen: HashSet<Center> = HashSet::new();
+    let mut groups = Vec::new();
+    for start in Center::ALL.iter().filter(|c| defined_centers.contains(c)) {
+        if seen.contains(start) {
+            continue;
+        }
+        let mut group = Vec::new();
+        let mut queue = vec![*start];
+        while let Some(node) = queue.pop() {
+            if !seen.insert(node) {
+                continue;
+            }
+            group.push(node);
+            if let Some(neighbors) = adj.get(&node) {
+                queue.extend(neighbors.iter().filter(|n| !seen.contains(n)));
+            }
+        }
+        group.sort();
+        groups.push(group);
+    }
+    groups
+}
+
+/// Inactive gates whose channel partner is active and whose channel spans two groups
+fn find_bridging_gates(all_gates: &HashSet<u8>, groups: &[Vec<Center>]) -> Vec<BridgingGate> {
+    if groups.len() < 2 {
+        return Vec::new();
+    }
+    let group_of = |c: Center| groups.iter().position(|g| g.contains(&c));
+    let mut bridges = Vec::new();
+    for ch in all_channels() {
+        match (group_of(ch.center1), group_of(ch.center2)) {
+            (Some(a), Some(b)) if a != b => {}
+            _ => continue,
+        }
+        let (gate, hanging_gate) = match (all_gates.contains(&ch.gate1), all_gates.contains(&ch.gate2)) {
+            (true, false) => (ch.gate2, ch.gate1),
+            (false, true) => (ch.gate1, ch.gate2),
+            _ => continue,
+        };
+        bridges.push(BridgingGate {
+            gate,
+            hanging_gate,
+            channel_name: ch.name,
+            joins: (ch.center1, ch.center2),
+        });
+    }
+    bridges.sort_by_key(|b| b.gate);
+    bridges
+}
+
+/// Serialize a center set as an array in bodygraph order
+fn serialize_center_set<S: Serializer>(centers: &HashSet<Center>, s: S) -> std::result::Result<S::Ok, S::Error> {
+    let mut sorted: Vec<&Center> = centers.iter().collect();
+    sorted.sort();
+    s.collect_seq(sorted)
+}
+
+pub(crate) fn find_activation<'a>(activations: &'a [Activation], planet: &'static str) -> Result<&'a Activation> {
+    activations.iter()
+        .find(|a| a.planet == planet)
+        .ok_or(HdError::MissingActivation(planet))
+}
+
+fn is_connected_to_throat(adj: &HashMap<Center, HashSet<Center>>, defined_centers: &HashSet<Center>) -> bool {
+    let motors = [Center::Sacral, Center::SolarPlexus, Center::HeartEgo, Center::Root];
+    for motor in &motors {
+        if defined_centers.contains(motor) && bfs_connected(adj, *motor, Center::Throat) {
+            return true;
+        }
+    }
+    false
+}
+
+fn bfs_connected(adj: &HashMap<Center, HashSet<Center>>, start: Center, end: Center) -> bool {
+    let mut visited = HashSet::new();
+    let mut queue = vec![start];
+    while let Some(node) = queue.pop() {
+        if node == end { return true; }
+        if visited.contains(&node) { continue; }
+        visited.insert(node);
+        if let Some(neighbors) = adj.get(&node) {
+            for n in neighbors {
+                if !visited.contains(n) {
+                    queue.push(*n);
+                }
+            }
+        }
+    }
+    false
+}
+
+fn determine_authority(defined_centers: &HashSet<Center>) -> &'static str {
+    if defined_centers.contains(&Center::SolarPlexus) {
+        "Emotional (Solar Plexus)"
+    } else if defined_centers.contains(&Center::Sacral) {
+        "Sacral"
+    } else if defined_centers.contains(&Center::Spleen) {
+        "Splenic"
+    } else if defined_centers.contains(&Center::HeartEgo) {
+        "Ego / Heart"
+    } else if defined_centers.contains(&Center::G) {
+        "Self-Projected"
+    } else if defined_centers.contains(&Center::Ajna) || defined_centers.contains(&Center::Head) {
+        "Mental / Environmental"
+    } else {
+        "Lunar (None)"
+    }
+}
@@ -0,0 +1,125 @@
+//! Time zone resolution for birth times.
+//!
+//! A zone is either a fixed UTC offset (`+2`, `-05:30`) or an IANA name
+//! (`Europe/Helsinki`) resolved through the tz database for the birth date,
+//! so historical offsets and DST are applied automatically.
+
+use chrono::{Duration, LocalResult, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use serde::Serialize;
+use crate::error::{HdError, Result};
+use crate::parse::parse_tz_offset;
+
+/// A time zone given on input
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Zone {
+    /// Fixed offset from UTC in hours
+    Fixed(f64),
+    /// IANA time zone database entry
+    Named(Tz),
+}
+
+/// How to resolve local times that are ambiguous (clocks set back) or
+/// non-existent (clocks set forward) around a DST transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DstPolicy {
+    /// Fail with an error describing both candidate offsets
+    #[default]
+    Reject,
+    /// Use the earlier of the two candidate UTC instants
+    Earlier,
+    /// Use the later of the two candidate UTC instants
+    Later,
+}
+
+/// Outcome of resolving a local birth time against a zone
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResolvedOffset {
+    /// UTC offset in hours applied to the local time
+    pub utc_offset: f64,
+    /// Local time fell into a DST overlap and was disambiguated by policy
+    pub ambiguous: bool,
+    /// Local time fell into a DST gap and was shifted by policy
+    pub nonexistent: bool,
+}
+
+impl Zone {
+    /// Parse a UTC offset or an IANA zone name
+    pub fn parse(s: &str) -> Result<Zone> {
+        if let Ok(offset) = parse_tz_offset(s) {
+            return Ok(Zone::Fixed(offset));
+        }
+        s.trim().parse::<Tz>()
+            .map(Zone::Named)
+            .map_err(|_| HdError::InvalidTimezone(s.to_string()))
+    }
+
+    /// Resolve the UTC offset in effect at a local date and decimal hour
+    pub fn resolve(&self, year: i32, month: u32, day: u32, hour: f64, policy: DstPolicy) -> Result<ResolvedOffset> {
+        let tz = match self {
+            Zone::Fixed(offset) => {
+                return Ok(ResolvedOffset { utc_offset: *offset, ambiguous: false, nonexistent: false });
+            }
+            Zone::Named(tz) => tz,
+        };
+        let local = local_datetime(year, month, day, hour)?;
+
+        match tz.from_local_datetime(&local) {
+            LocalResult::Single(dt) => Ok(ResolvedOffset {
+                utc_offset: offset_hours(dt.offset().fix().local_minus_utc()),
+                ambiguous: false,
+                nonexistent: false,
+            }),
+            LocalResult::Ambiguous(a, b) => {
+                let (early, late) = (offset_hours(a.offset().fix().local_minus_utc()),
+                    offset_hours(b.offset().fix().local_minus_utc()));
+                // A larger offset means an earlier UTC instant for the same local time
+                let (earlier, later) = if early >= late { (early, late) } else { (late, early) };
+                let utc_offset = match policy {
+                    DstPolicy::Reject => return Err(HdError::AmbiguousLocalTime(
+                        describe(tz, &local, earlier, later))),
+                    DstPolicy::Earlier => earlier,
+                    DstPolicy::Later => later,
+                };
+                Ok(ResolvedOffset { utc_offset, ambiguous: true, nonexistent: false })
+            }
+            LocalResult::None => {
+                // Offsets in effect on either side of the gap
+                let before = offset_at(tz, local - Duration::days(1));
+                let after = offset_at(tz, local + Duration::days(1));
+                let (earlier, later) = if after >= before { (after, before) } else { (before, after) };
+                let utc_offset = match policy {
+                    DstPolicy::Reject => return Err(HdError::NonexistentLocalTime(
+                        describe(tz, &local, earlier, later))),
+                    DstPolicy::Earlier => earlier,
+                    DstPolicy::Later => later,
+                };
+                Ok(ResolvedOffset { utc_offset, ambiguous: false, nonexistent: true })
+            }
+        }
+    }
+}
+
+fn local_datetime(year: i32, month: u32, day: u32, hour: f64) -> Result<NaiveDateTime> {
+    let date = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| HdError::InvalidDate(format!("{:04}-{:02}-{:02}", year, month, day)))?;
+    if !(0.0..24.0).contains(&hour) {
+        return Err(HdError::InvalidTime(format!("{} h", hour)));
+    }
+    let secs = (hour * 3600.0).round() as i64;
+    Ok(date.and_hms_opt(0, 0, 0).unwrap_or_default() + Duration::seconds(secs))
+}
+
+/// Offset in effect at a naive time treated as UTC (only used away from transitions)
+fn offset_at(tz: &Tz, naive: NaiveDateTime) -> f64 {
+    offset_hours(tz.offset_from_utc_datetime(&naive).fix().local_minus_utc())
+}
+
+fn offset_hours(seconds: i32) -> f64 {
+    seconds as f64 / 3600.0
+}
+
+fn describe(tz: &Tz, local: &NaiveDateTime, earlier: f64, later: f64) -> String {
+    format!("{} {} (UTC{:+} or UTC{:+})", local.format("%Y-%m-%d %H:%M"), tz.name(), earlier, later)
+}
@@ -0,0 +1,134 @@
+//! Variables: the four arrows (Four Transformations).
+//!
+//! Each arrow is read from one activation: Design Sun (Determination),
+//! Design Node (Environment), Personality Sun (Motivation) and Personality
+//! Node (Perspective). The tone sets the arrow direction (1-3 left, 4-6
+//! right) and the color names the variable.
+
+use serde::Serialize;
+use crate::chart::{Activation, Chart};
+use crate::error::Result;
+use crate::types::find_activation;
+
+/// Direction of a variable arrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Arrow {
+    Left,
+    Right,
+}
+
+impl Arrow {
+    pub fn from_tone(tone: u8) -> Arrow {
+        if tone <= 3 { Arrow::Left } else { Arrow::Right }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Arrow::Left => "Left",
+            Arrow::Right => "Right",
+        }
+    }
+}
+
+/// One of the four variables
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variable {
+    pub arrow: Arrow,
+    /// Activation the variable is read from, e.g. "Design Sun"
+    pub source: &'static str,
+    pub color: u8,
+    pub tone: u8,
+    /// Arrow orientation keynote, e.g. "Active" / "Passive"
+    pub orientation: &'static str,
+    /// Color name, e.g. "Appetite" or "Markets"
+    pub name: &'static str,
+    /// Left/right refinement of the color, where the system defines one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refinement: Option<&'static str>,
+}
+
+/// The four arrows of a chart
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Variables {
+    /// Top left: Design Sun (digestion)
+    pub determination: Variable,
+    /// Bottom left: Design Node
+    pub environment: Variable,
+    /// Top right: Personality Sun
+    pub motivation: Variable,
+    /// Bottom right: Personality Node (view)
+    pub perspective: Variable,
+}
+
+impl Variables {
+    /// Compact notation in arrow order, e.g. "PLR DLL"
+    /// (Personality top/bottom, Design top/bottom)
+    pub fn notation(&self) -> String {
+        let c = |v: &Variable| v.arrow.name().chars().next().unwrap_or('?');
+        format!("P{}{} D{}{}",
+            c(&self.motivation), c(&self.perspective),
+            c(&self.determination), c(&self.environment))
+    }
+}
+
+const DETERMINATION: [&str; 6] = ["Appetite", "Taste", "Thirst", "Touch", "Sound", "Light"];
+const DETERMINATION_REFINEMENTS: [(&str, &str); 6] = [
+    ("Consecutive", "Alternating"),
+    ("Open", "Closed"),
+    ("Hot", "Cold"),
+    ("Calm", "Nervous"),
+    ("High", "Low"),
+    ("Direct", "Indirect"),
+];
+const ENVIRONMENT: [&str; 6] = ["Caves", "Markets", "Kitchens", "Mountains", "Valleys", "Shores"];
+const ENVIRONMENT_REFINEMENTS: [(&str, &str); 6] = [
+    ("Selective", "Blending"),
+    ("Internal", "External"),
+    ("Wet", "Dry"),
+    ("Active", "Passive"),
+    ("Narrow", "Wide"),
+    ("Natural", "Artificial"),
+];
+const MOTIVATION: [&str; 6] = ["Fear", "Hope", "Desire", "Need", "Guilt", "Innocence"];
+const PERSPECTIVE: [&str; 6] = ["Survival", "Possibility", "Power", "Wanting", "Probability", "Personal"];
+
+/// Read the four variables from a chart's Sun and North Node activations
+pub fn variables(chart: &Chart) -> Result<Variables> {
+    let d_sun = find_activation(&chart.design, "Sun")?;
+    let d_node = find_activation(&chart.design, "North Node")?;
+    let p_sun = find_activation(&chart.personality, "Sun")?;
+    let p_node = find_activation(&chart.personality, "North Node")?;
+
+    Ok(Variables {
+        determination: variable(d_sun, "Design Sun", ("Active", "Passive"), &DETERMINATION, Some(&DETERMINATION_REFINEMENTS)),
+        environment: variable(d_node, "Design Node", ("Observed", "Observer"), &ENVIRONMENT, Some(&ENVIRONMENT_REFINEMENTS)),
+        motivation: variable(p_sun, "Personality Sun", ("Strategic", "Receptive"), &MOTIVATION, None),
+        perspective: variable(p_node, "Personality Node", ("Focused", "Peripheral"), &PERSPECTIVE, None),
+    })
+}
+
+fn variable(
+    a: &Activation,
+    source: &'static str,
+    orientations: (&'static str, &'static str),
+    names: &[&'static str; 6],
+    refinements: Option<&[(&'static str, &'static str); 6]>,
+) -> Variable {
+    let arrow = Arrow::from_tone(a.tone);
+    let idx = (a.color.clamp(1, 6) - 1) as usize;
+    let pick = |pair: (&'static str, &'static str)| match arrow {
+        Arrow::Left => pair.0,
+        Arrow::Right => pair.1,
+    };
+    Variable {
+        arrow,
+        source,
+        color: a.color,
+        tone: a.tone,
+        orientation: pick(orientations),
+        name: names[idx],
+        refinement: refinements.map(|r| pick(r[idx])),
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::{TimeZone, Utc};
+use hd_core::chart::{calculate_chart_at_with, calculate_transit_at};
+use hd_core::{analyze, calculate_chart, calculate_chart_at, calculate_chart_with, ChartOptions, ExtraBody, NodeModel};
+
+#[test]
+fn extra_bodies_do_not_change_definition() {
+    let plain = calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    let extended = calculate_chart_with(1985, 6, 15, 14.5, 3.0, &ChartOptions { bodies: vec![ExtraBody::Lilith], ..ChartOptions::default() }).unwrap();
+    assert!(plain.personality_extras.is_empty() && plain.design_extras.is_empty());
+    assert_eq!(extended.personality_extras.len(), 1);
+    assert_eq!(extended.design_extras[0].planet, "Lilith");
+    assert_eq!(extended.personality.len(), 13);
+    assert_eq!(plain.gates(), extended.gates());
+
+    let (a, b) = (analyze(&plain).unwrap(), analyze(&extended).unwrap());
+    assert_eq!(a.hd_type.name(), b.hd_type.name());
+    assert_eq!(a.defined_centers, b.defined_centers);
+    assert_eq!(a.defined_channels.len(), b.defined_channels.len());
+}
+
+#[test]
+fn parses_extra_body_names() {
+    assert_eq!(ExtraBody::from_name("chiron"), Some(ExtraBody::Chiron));
+    assert_eq!(ExtraBody::from_name(" LILITH "), Some(ExtraBody::Lilith));
+    assert_eq!(ExtraBody::from_name("ceres"), None);
+}
+
+fn node_chart(node_model: NodeModel) -> hd_core::Chart {
+    calculate_chart_with(1985, 4, 1, 12.0, 0.0, &ChartOptions { node_model, ..ChartOptions::default() }).unwrap()
+}
+
+#[test]
+fn node_models_differ_only_in_the_nodes() {
+    let (true_node, mean_node) = (node_chart(NodeModel::True), node_chart(NodeModel::Mean));
+    assert_eq!(calculate_chart(1985, 4, 1, 12.0, 0.0).unwrap().node_model, NodeModel::True);
+    assert_eq!(mean_node.node_model, NodeModel::Mean);
+
+    // The true node had just crossed into Gate 2 while the mean node was still in 23
+    let north = |c: &hd_core::Chart| c.personality.iter().find(|a| a.planet == "North Node").unwrap().clone();
+    assert_eq!((north(&true_node).gate, north(&true_node).line), (2, 6));
+    assert_eq!((north(&mean_node).gate, north(&mean_node).line), (23, 2));
+    assert!((north(&true_node).longitude - north(&mean_node).longitude).abs() < 2.0);
+
+    for (t, m) in true_node.personality.iter().zip(&mean_node.personality)
+        .chain(true_node.design.iter().zip(&mean_node.design))
+    {
+        assert_eq!(t.planet, m.planet);
+        if !t.planet.ends_with("Node") {
+            assert_eq!(t.longitude, m.longitude, "{}", t.planet);
+        }
+    }
+    let south = mean_node.personality.iter().find(|a| a.planet == "South Node").unwrap();
+    assert!(((south.longitude - north(&mean_node).longitude).rem_euclid(360.0) - 180.0).abs() < 1e-9);
+}
+
+#[test]
+fn mean_node_matches_reference_at_j2000() {
+    // Meeus, Astronomical Algorithms: mean ascending node 125.0445° at J2000.0
+    let at = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap();
+    let options = ChartOptions { node_model: NodeModel::Mean, ..ChartOptions::default() };
+    let chart = calculate_chart_at_with(at, &options).unwrap();
+    let north = chart.personality.iter().find(|a| a.planet == "North Node").unwrap();
+    assert!((north.longitude - 125.0445).abs() < 0.01, "{}", north.longitude);
+}
+
+/// Days between the Design and the birth moment, checking the 88° arc
+fn design_days(birth: chrono::DateTime<Utc>) -> f64 {
+    let chart = hd_core::calculate_chart_at(birth).unwrap();
+    let sun_at = |at| calculate_transit_at(at).unwrap()[0].longitude;
+    let arc = (sun_at(birth) - sun_at(chart.design_at)).rem_euclid(360.0);
+    assert!((arc - 88.0).abs() < 1e-6, "{} arc {}", birth, arc);
+    // design_at is rounded to the millisecond
+    assert!((chart.design[0].longitude - sun_at(chart.design_at)).abs() < 1e-6);
+    (birth - chart.design_at).num_milliseconds() as f64 / 86_400_000.0
+}
+
+#[test]
+fn design_is_88_degrees_before_birth_around_perihelion_and_aphelion() {
+    // The Sun is fastest in early January and slowest in early July, so the
+    // 88° arc takes fewer days when it spans perihelion than aphelion
+    let perihelion = design_days(Utc.with_ymd_and_hms(1990, 3, 1, 6, 0, 0).unwrap());
+    let aphelion = design_days(Utc.with_ymd_and_hms(1990, 9, 1, 6, 0, 0).unwrap());
+    assert!((86.0..88.0).contains(&perihelion), "{}", perihelion);
+    assert!((91.0..93.0).contains(&aphelion), "{}", aphelion);
+    for (m, d) in [(1, 3), (7, 4), (4, 2), (10, 3)] {
+        let days = design_days(Utc.with_ymd_and_hms(1990, m, d, 12, 0, 0).unwrap());
+        assert!((86.0..93.0).contains(&days), "{}-{}: {}", m, d, days);
+    }
+}
+
+#[test]
+fn design_crosses_year_boundaries() {
+    // New Year's birth: the Design falls in early October of the year before
+    let chart = hd_core::calculate_chart(2000, 1, 1, 0.5, 0.0).unwrap();
+    assert_eq!(chart.design_at.format("%Y-%m").to_string(), "1999-10");
+    design_days(Utc.with_ymd_and_hms(2000, 1, 1, 0, 30, 0).unwrap());
+
+    // Birth at the end of March: the Design falls around New Year's Eve
+    let birth = Utc.with_ymd_and_hms(2001, 3, 29, 23, 0, 0).unwrap();
+    let design_at = hd_core::calculate_chart_at(birth).unwrap().design_at;
+    assert!(design_at > Utc.with_ymd_and_hms(2000, 12, 28, 0, 0, 0).unwrap(), "{}", design_at);
+    assert!(design_at < Utc.with_ymd_and_hms(2001, 1, 4, 0, 0, 0).unwrap(), "{}", design_at);
+    design_days(birth);
+}
+
+#[test]
+fn charts_computed_in_parallel_match_sequential_ones() {
+    let births: Vec<_> = (0..32)
+        .map(|i| Utc.with_ymd_and_hms(1950 + i, 1 + (i as u32 % 12), 1 + (i as u32 % 28), i as u32 % 24, 0, 0).unwrap())
+        .collect();
+    let sequential: Vec<_> = births.iter().map(|&b| calculate_chart_at(b).unwrap()).collect();
+    let parallel: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = births.iter().map(|&b| scope.spawn(move || calculate_chart_at(b).unwrap())).collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    for (s, p) in sequential.iter().zip(&parallel) {
+        let lons = |c: &hd_core::Chart| c.personality.iter().chain(&c.design).map(|a| a.longitude).collect::<Vec<_>>();
+        assert_eq!(lons(s), lons(p));
+        assert_eq!(s.design_at, p.design_at);
+    }
+}
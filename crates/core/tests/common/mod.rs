@@ -0,0 +1,10 @@
+use hd_core::{calculate_chart, Chart};
+
+/// A real chart whose activations hold exactly the given gates
+pub fn chart_with_gates(gates: &[u8]) -> Chart {
+    let mut chart = calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    for (i, a) in chart.personality.iter_mut().chain(chart.design.iter_mut()).enumerate() {
+        a.gate = gates[i % gates.len()];
+    }
+    chart
+}
@@ -0,0 +1,41 @@
+mod common;
+
+use common::chart_with_gates;
+use hd_core::{composite, ConnectionKind, Person};
+
+#[test]
+fn channels_are_classified_by_who_holds_which_gates() {
+    // Both: 1-8; A alone: 2-14; A whole, B one gate: 25-51; B alone: 24-61;
+    // one gate each: 6-59 (A 59, B 6)
+    let a = chart_with_gates(&[1, 8, 2, 14, 25, 51, 59]);
+    let b = chart_with_gates(&[1, 8, 25, 6, 24, 61]);
+    let c = composite(&a, &b);
+
+    let kind = |gate1: u8, gate2: u8| {
+        let ch = c.channels.iter().find(|ch| (ch.channel.gate1, ch.channel.gate2) == (gate1, gate2))
+            .unwrap_or_else(|| panic!("channel {gate1}-{gate2} not defined"));
+        (ch.kind, ch.holder)
+    };
+    assert_eq!(c.channels.len(), 5);
+    assert_eq!(kind(1, 8), (ConnectionKind::Companionship, None));
+    assert_eq!(kind(2, 14), (ConnectionKind::Dominance, Some(Person::A)));
+    assert_eq!(kind(24, 61), (ConnectionKind::Dominance, Some(Person::B)));
+    assert_eq!(kind(25, 51), (ConnectionKind::Compromise, Some(Person::A)));
+    assert_eq!(kind(6, 59), (ConnectionKind::Electromagnetic, None));
+
+    let counts = ConnectionKind::ALL.map(|k| c.count(k));
+    assert_eq!(counts, [1, 1, 2, 1]);
+    assert_eq!(c.combined.defined_channels.len(), 5);
+}
+
+#[test]
+fn composite_is_symmetric_up_to_the_holder() {
+    let a = chart_with_gates(&[1, 8, 2, 14, 25, 51, 59]);
+    let b = chart_with_gates(&[1, 8, 25, 6, 24, 61]);
+    let (ab, ba) = (composite(&a, &b), composite(&b, &a));
+    for kind in ConnectionKind::ALL {
+        assert_eq!(ab.count(kind), ba.count(kind), "{}", kind.name());
+    }
+    let swapped = ba.channels.iter().find(|ch| ch.channel.gate1 == 25).unwrap();
+    assert_eq!(swapped.holder, Some(Person::B));
+}
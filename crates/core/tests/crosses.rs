@@ -0,0 +1,106 @@
+use std::collections::{HashMap, HashSet};
+use hd_core::crosses::{find_cross, incarnation_cross, Angle, Quarter, CROSSES};
+use hd_core::gates::{longitude_to_gate_line, GATE_ORDER, GATE_SIZE, HD_START_DEGREE};
+
+#[test]
+fn table_has_every_gate_at_every_angle() {
+    assert_eq!(CROSSES.len(), 192);
+    let keys: HashSet<(Angle, u8)> = CROSSES.iter().map(|c| (c.angle, c.gates.0)).collect();
+    assert_eq!(keys.len(), 192);
+    for gate in 1..=64 {
+        for angle in [Angle::RightAngle, Angle::Juxtaposition, Angle::LeftAngle] {
+            assert!(find_cross(gate, angle).is_some(), "missing {:?} cross for gate {}", angle, gate);
+        }
+    }
+}
+
+#[test]
+fn right_angle_crosses_share_names_across_four_gates() {
+    let mut by_name: HashMap<&str, Vec<u8>> = HashMap::new();
+    for c in CROSSES.iter().filter(|c| c.angle == Angle::RightAngle) {
+        let base = c.name.trim_end_matches(|ch: char| ch.is_ascii_digit() || ch == ' ');
+        by_name.entry(base).or_default().push(c.gates.0);
+    }
+    assert_eq!(by_name.len(), 16);
+    for (name, gates) in &by_name {
+        assert_eq!(gates.len(), 4, "{}", name);
+    }
+    let left: HashSet<&str> = CROSSES.iter()
+        .filter(|c| c.angle == Angle::LeftAngle)
+        .map(|c| c.name.trim_end_matches(|ch: char| ch.is_ascii_digit() || ch == ' '))
+        .collect();
+    assert_eq!(left.len(), 32);
+}
+
+/// The design Sun is 88° of solar arc behind the personality Sun, and both
+/// Earths are opposite their Sun
+#[test]
+fn design_gates_lie_88_degrees_back_on_the_wheel() {
+    for c in &CROSSES {
+        let index = GATE_ORDER.iter().position(|&g| g == c.gates.0).unwrap();
+        // A personality Sun position within the gate that gives a profile of this angle
+        let fraction = match c.angle {
+            Angle::RightAngle => 0.25,
+            Angle::Juxtaposition => 0.655,
+            Angle::LeftAngle => 0.9,
+        };
+        let p_sun = HD_START_DEGREE + (index as f64 + fraction) * GATE_SIZE;
+        let d_sun = p_sun - 88.0;
+        let (p, p_line) = longitude_to_gate_line(p_sun);
+        let (d, d_line) = longitude_to_gate_line(d_sun);
+        assert_eq!(p, c.gates.0, "{}", c.name);
+        assert_eq!(Angle::from_profile((p_line, d_line)), c.angle, "{} {}/{}", c.name, p_line, d_line);
+
+        let earth = |sun: f64| longitude_to_gate_line(sun + 180.0).0;
+        assert_eq!((earth(p_sun), d, earth(d_sun)), (c.gates.1, c.gates.2, c.gates.3), "{}", c.name);
+    }
+}
+
+#[test]
+fn known_crosses() {
+    let sphinx = find_cross(1, Angle::RightAngle).unwrap();
+    assert_eq!(sphinx.name, "Right Angle Cross of the Sphinx 4");
+    assert_eq!(sphinx.gates, (1, 2, 7, 13));
+    assert_eq!(find_cross(13, Angle::RightAngle).unwrap().name, "Right Angle Cross of the Sphinx 1");
+    assert_eq!(find_cross(27, Angle::RightAngle).unwrap().gates, (27, 28, 41, 31));
+    assert_eq!(find_cross(63, Angle::Juxtaposition).unwrap().name, "Juxtaposition Cross of Doubts");
+    assert_eq!(find_cross(34, Angle::Juxtaposition).unwrap().name, "Juxtaposition Cross of Power");
+    assert_eq!(find_cross(13, Angle::Juxtaposition).unwrap().gates, (13, 7, 43, 23));
+    assert_eq!(find_cross(38, Angle::Juxtaposition).unwrap().gates, (38, 39, 57, 51));
+    assert_eq!(find_cross(54, Angle::Juxtaposition).unwrap().gates, (54, 53, 32, 42));
+    let wishes = find_cross(3, Angle::LeftAngle).unwrap();
+    assert_eq!(wishes.name, "Left Angle Cross of Wishes 1");
+    assert_eq!(wishes.gates, (3, 50, 41, 31));
+    assert_eq!(find_cross(50, Angle::LeftAngle).unwrap().name, "Left Angle Cross of Wishes 2");
+}
+
+#[test]
+fn angle_follows_profile() {
+    for p in [(1, 3), (1, 4), (2, 4), (2, 5), (3, 5), (3, 6), (4, 6)] {
+        assert_eq!(Angle::from_profile(p), Angle::RightAngle);
+    }
+    assert_eq!(Angle::from_profile((4, 1)), Angle::Juxtaposition);
+    for p in [(5, 1), (5, 2), (6, 2), (6, 3)] {
+        assert_eq!(Angle::from_profile(p), Angle::LeftAngle);
+    }
+}
+
+#[test]
+fn quarters_start_at_gates_13_2_7_1() {
+    assert_eq!(Quarter::of_gate(13), Quarter::Initiation);
+    assert_eq!(Quarter::of_gate(24), Quarter::Initiation);
+    assert_eq!(Quarter::of_gate(2), Quarter::Civilization);
+    assert_eq!(Quarter::of_gate(33), Quarter::Civilization);
+    assert_eq!(Quarter::of_gate(7), Quarter::Duality);
+    assert_eq!(Quarter::of_gate(44), Quarter::Duality);
+    assert_eq!(Quarter::of_gate(1), Quarter::Mutation);
+    assert_eq!(Quarter::of_gate(19), Quarter::Mutation);
+}
+
+#[test]
+fn chart_cross_uses_actual_gates() {
+    let cross = incarnation_cross((12, 11, 36, 6), (2, 4)).unwrap();
+    assert_eq!(cross.name, "Right Angle Cross of Eden 2");
+    assert_eq!(cross.quarter, Quarter::Civilization);
+    assert_eq!(cross.gates, (12, 11, 36, 6));
+}
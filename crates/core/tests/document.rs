@@ -0,0 +1,58 @@
+use hd_core::document::SCHEMA_VERSION;
+use hd_core::{analyze, calculate_chart, BirthInfo, ChartDocument};
+use serde_json::Value;
+
+fn keys(v: &Value) -> Vec<&str> {
+    let mut keys: Vec<&str> = v.as_object().unwrap().keys().map(String::as_str).collect();
+    keys.sort();
+    keys
+}
+
+/// The web app reads these names; renaming one is a breaking change that
+/// needs a new SCHEMA_VERSION
+#[test]
+fn chart_document_keeps_its_versioned_shape() {
+    let chart = calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    let analysis = analyze(&chart).unwrap();
+    let birth = BirthInfo {
+        date: "1985-06-15".to_string(),
+        time: "14:30".to_string(),
+        tz: "+3".to_string(),
+        utc_offset: 3.0,
+        location: String::new(),
+        place: None,
+    };
+    let doc = serde_json::to_value(ChartDocument::new(birth, &chart, &analysis)).unwrap();
+
+    assert_eq!(SCHEMA_VERSION, 1);
+    assert_eq!(doc["version"], SCHEMA_VERSION);
+    assert_eq!(doc["nodeModel"], "true");
+    assert_eq!(keys(&doc), ["analysis", "birth", "chart", "nodeModel", "version"]);
+    assert_eq!(keys(&doc["birth"]), ["date", "location", "place", "time", "tz", "utcOffset"]);
+    assert_eq!(keys(&doc["chart"]), ["design", "designAt", "nodeModel", "personality"]);
+    assert_eq!(keys(&doc["chart"]["personality"][0]),
+        ["base", "color", "gate", "line", "linePosition", "longitude", "planet", "tone"]);
+    assert_eq!(keys(&doc["analysis"]), [
+        "allGates", "authority", "bridgingGates", "circuitry", "cross", "definedCenters", "definedChannels",
+        "definition", "definitionGroups", "designGates", "incarnationCross", "openCenters", "personalityGates",
+        "profile", "profileName", "type", "variables",
+    ]);
+    assert_eq!(doc["chart"]["personality"].as_array().unwrap().len(), 13);
+    assert_eq!(doc["analysis"]["type"], analysis.hd_type.name());
+}
+
+#[test]
+fn transit_overlay_is_only_present_when_requested() {
+    let chart = calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    let analysis = analyze(&chart).unwrap();
+    let birth = BirthInfo {
+        date: String::new(),
+        time: String::new(),
+        tz: String::new(),
+        utc_offset: 3.0,
+        location: String::new(),
+        place: None,
+    };
+    let doc = serde_json::to_value(ChartDocument::new(birth, &chart, &analysis)).unwrap();
+    assert!(doc.get("transit").is_none());
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use hd_core::gates::{all_channels, gate_center, gate_circuits};
+use hd_core::{Center, Circuit};
+
+#[test]
+fn every_gate_sits_in_the_center_its_channels_name() {
+    let mut centers: HashMap<u8, Center> = HashMap::new();
+    for ch in all_channels() {
+        for (gate, center) in [(ch.gate1, ch.center1), (ch.gate2, ch.center2)] {
+            assert_eq!(*centers.entry(gate).or_insert(center), center, "gate {gate}");
+        }
+    }
+    assert_eq!(centers.len(), 64);
+    for gate in 1..=64 {
+        assert_eq!(gate_center(gate), Some(centers[&gate]), "gate {gate}");
+    }
+}
+
+#[test]
+fn centers_hold_their_gates() {
+    let mut counts: HashMap<Center, usize> = HashMap::new();
+    for gate in 1..=64 {
+        *counts.entry(gate_center(gate).unwrap()).or_default() += 1;
+    }
+    let expected = [
+        (Center::Head, 3), (Center::Ajna, 6), (Center::Throat, 11), (Center::G, 8), (Center::HeartEgo, 4),
+        (Center::SolarPlexus, 7), (Center::Sacral, 9), (Center::Spleen, 7), (Center::Root, 9),
+    ];
+    assert_eq!(counts, expected.into_iter().collect());
+    for gate in [1, 2, 7, 10, 13, 15, 25, 46] {
+        assert_eq!(gate_center(gate), Some(Center::G), "gate {gate}");
+    }
+    assert_eq!((gate_center(0), gate_center(65)), (None, None));
+}
+
+#[test]
+fn gate_circuits_follow_the_gate_channels() {
+    for gate in 1..=64 {
+        let circuits = gate_circuits(gate);
+        assert!(!circuits.is_empty(), "gate {gate}");
+        for ch in all_channels().iter().filter(|ch| ch.gate1 == gate || ch.gate2 == gate) {
+            assert!(circuits.contains(&ch.circuit), "gate {gate}");
+        }
+    }
+    // Gate 10 takes part in three Integration channels, listed once
+    assert_eq!(gate_circuits(10), [Circuit::Integration]);
+    assert_eq!(gate_circuits(25), [Circuit::Individual]);
+    assert_eq!(gate_circuits(41), [Circuit::Collective]);
+    assert!(gate_circuits(0).is_empty());
+}
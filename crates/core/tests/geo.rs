@@ -0,0 +1,72 @@
+use hd_core::{Gazetteer, HdError};
+
+/// GeoNames rows: id, name, ascii name, alternate names, lat, lon, feature
+/// class and code, country, cc2, admin1-4, population, elevation, dem, zone,
+/// modification date
+const GEONAMES: &str = "\
+655808\tJoensuu\tJoensuu\tJoensuu,Jojensuu\t62.60118\t29.76316\tP\tPPLA\tFI\t\t13\t\t\t\t76551\t\t81\tEurope/Helsinki\t2019-09-05
+5746545\tPortland\tPortland\tPDX,Portlend\t45.52345\t-122.67621\tP\tPPLA2\tUS\t\tOR\t051\t\t\t652503\t15\t16\tAmerica/Los_Angeles\t2019-09-05
+4975802\tPortland\tPortland\t\t43.66147\t-70.25533\tP\tPPLA2\tUS\t\tME\t005\t\t\t66881\t9\t17\tAmerica/New_York\t2019-09-05
+2643743\tLondon\tLondon\tLondres,Lontoo\t51.50853\t-0.12574\tP\tPPLC\tGB\t\tENG\tGLA\t\t\t8961989\t\t25\tEurope/London\t2019-09-05
+6058560\tLondon\tLondon\t\t42.98339\t-81.23304\tP\tPPL\tCA\t\t08\t\t\t\t346765\t\t252\tAmerica/Toronto\t2019-09-05
+not a geonames row
+";
+
+fn gazetteer() -> Gazetteer {
+    Gazetteer::from_geonames(GEONAMES).unwrap()
+}
+
+#[test]
+fn reads_geonames_rows_and_skips_others() {
+    let gazetteer = gazetteer();
+    assert_eq!(gazetteer.len(), 5);
+
+    let joensuu = gazetteer.resolve("Joensuu").unwrap();
+    assert_eq!((joensuu.name.as_str(), joensuu.country_code.as_str(), joensuu.admin1.as_str()), ("Joensuu", "FI", "13"));
+    assert_eq!((joensuu.latitude, joensuu.longitude, joensuu.population), (62.60118, 29.76316, 76551));
+    assert_eq!(joensuu.timezone, "Europe/Helsinki");
+    assert_eq!(joensuu.label(), "Joensuu, Finland");
+
+    assert!(matches!(Gazetteer::from_geonames("no\tplaces\there\n"), Err(HdError::Gazetteer(_))));
+}
+
+#[test]
+fn exact_and_alternate_names_resolve() {
+    let gazetteer = gazetteer();
+    assert_eq!(gazetteer.resolve("joensuu").unwrap().timezone, "Europe/Helsinki");
+    assert_eq!(gazetteer.resolve("Lontoo").unwrap().country_code, "GB");
+    assert_eq!(gazetteer.resolve("Portland, OR").unwrap().timezone, "America/Los_Angeles");
+    assert_eq!(gazetteer.resolve("London, Canada").unwrap().timezone, "America/Toronto");
+    assert_eq!(Gazetteer::bundled().resolve("Helsinki, Finland").unwrap().timezone, "Europe/Helsinki");
+}
+
+#[test]
+fn misspellings_within_the_edit_limit_resolve() {
+    let gazetteer = gazetteer();
+    assert_eq!(gazetteer.resolve("Joensu").unwrap().name, "Joensuu");
+    assert_eq!(gazetteer.resolve("Jonsuu").unwrap().name, "Joensuu");
+    // Seven letters allow one edit, not two
+    assert!(matches!(gazetteer.resolve("Jonsu"), Err(HdError::UnknownLocation(_))));
+}
+
+#[test]
+fn shared_names_list_the_candidates() {
+    match gazetteer().resolve("Portland") {
+        Err(HdError::AmbiguousLocation { query, candidates }) => {
+            assert_eq!(query, "Portland");
+            assert_eq!(candidates.len(), 2);
+            // Larger places are listed first
+            assert!(candidates[0].starts_with("Portland, OR, United States"), "{:?}", candidates);
+            assert!(candidates[1].starts_with("Portland, ME, United States"), "{:?}", candidates);
+        }
+        other => panic!("expected an ambiguous location, got {:?}", other),
+    }
+}
+
+#[test]
+fn unknown_places_fail() {
+    let gazetteer = gazetteer();
+    assert!(matches!(gazetteer.resolve("Atlantis"), Err(HdError::UnknownLocation(q)) if q == "Atlantis"));
+    assert!(matches!(gazetteer.resolve("Joensuu, Sweden"), Err(HdError::UnknownLocation(_))));
+    assert!(gazetteer.search("").is_empty());
+}
@@ -0,0 +1,40 @@
+mod common;
+
+use common::chart_with_gates;
+use hd_core::{penta, Center, HdError};
+
+#[test]
+fn three_people_fill_the_penta_together() {
+    // Gate 41 is outside the Penta and only pads the charts
+    let group = [
+        chart_with_gates(&[7, 31, 1, 41]),
+        chart_with_gates(&[8, 5, 41]),
+        chart_with_gates(&[15, 13, 41]),
+    ];
+    let p = penta(&group).unwrap();
+    assert_eq!(p.size, 3);
+    assert_eq!(p.filled, [31, 8, 7, 1, 13, 15, 5]);
+    assert_eq!(p.missing, [33, 2, 46, 14, 29]);
+    assert_eq!(p.gate(1).unwrap().members, [0]);
+    assert_eq!(p.gate(8).unwrap().center, Center::Throat);
+    assert_eq!(p.gate(5).unwrap().center, Center::Sacral);
+
+    let role = |name: &str| p.roles.iter().find(|r| r.role == name).unwrap();
+    assert_eq!(p.roles.len(), 6);
+    // Held by one member alone
+    assert!(role("Leadership").covered);
+    assert_eq!(role("Leadership").held_by, [0]);
+    // Filled across members, held by nobody
+    assert!(role("Creative Direction").covered && role("Creative Direction").held_by.is_empty());
+    assert!(role("Flow").covered && role("Flow").held_by.is_empty());
+    for open in ["Memory", "Resources", "Commitment"] {
+        assert!(!role(open).covered, "{}", open);
+    }
+}
+
+#[test]
+fn penta_needs_three_to_five_people() {
+    let chart = chart_with_gates(&[41]);
+    assert!(matches!(penta(&[chart.clone(), chart.clone()]), Err(HdError::GroupSize(2))));
+    assert!(matches!(penta(&vec![chart; 6]), Err(HdError::GroupSize(6))));
+}
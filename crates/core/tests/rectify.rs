@@ -0,0 +1,34 @@
+use chrono::{Duration, TimeZone, Utc};
+use hd_core::{calculate_chart_at, rectify, ChartOptions, ChartWindow, HdError};
+
+#[test]
+fn rectification_boundaries_change_activations() {
+    let from = Utc.with_ymd_and_hms(1985, 6, 15, 3, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(1985, 6, 15, 9, 0, 0).unwrap();
+    let result = rectify(from, to, &ChartOptions::default()).unwrap();
+    assert!(result.boundaries.len() >= 6, "{:?}", result.boundaries);
+
+    let lines = |at| {
+        let chart = calculate_chart_at(at).unwrap();
+        chart.personality.iter().chain(&chart.design).map(|a| (a.planet, a.gate, a.line)).collect::<Vec<_>>()
+    };
+    for b in &result.boundaries {
+        let (before, after) = (lines(b.at - Duration::milliseconds(5)), lines(b.at + Duration::milliseconds(5)));
+        let changed = before.iter().zip(&after).filter(|(x, y)| x != y).count();
+        assert_eq!(changed, b.changes.len(), "{:?}", b);
+    }
+
+    // Sub-windows tile the window, and neighbours differ in their reading
+    assert_eq!(result.windows.first().unwrap().from, from);
+    assert_eq!(result.windows.last().unwrap().to, to);
+    for pair in result.windows.windows(2) {
+        assert_eq!(pair[0].to, pair[1].from);
+        let channels = |w: &ChartWindow| w.analysis.defined_channels.iter().map(|c| (c.gate1, c.gate2)).collect::<Vec<_>>();
+        assert!(channels(&pair[0]) != channels(&pair[1])
+            || pair[0].analysis.profile != pair[1].analysis.profile
+            || pair[0].analysis.incarnation_cross != pair[1].analysis.incarnation_cross
+            || pair[0].analysis.hd_type.name() != pair[1].analysis.hd_type.name()
+            || pair[0].analysis.authority != pair[1].analysis.authority);
+    }
+    assert!(matches!(rectify(to, from, &ChartOptions::default()), Err(HdError::InvalidRange(_))));
+}
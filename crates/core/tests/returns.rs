@@ -0,0 +1,65 @@
+use chrono::{Duration, TimeZone, Utc};
+use hd_core::returns::{returns, Cycle};
+use hd_core::HdError;
+
+/// Angular distance in degrees, in [0, 180]
+fn separation(a: f64, b: f64) -> f64 {
+    let d = (a - b).rem_euclid(360.0);
+    d.min(360.0 - d)
+}
+
+fn longitude(chart: &hd_core::Chart, planet: &str) -> f64 {
+    chart.personality.iter().find(|a| a.planet == planet).unwrap().longitude
+}
+
+#[test]
+fn saturn_return_reaches_natal_longitude() {
+    let birth = Utc.with_ymd_and_hms(1985, 6, 15, 11, 30, 0).unwrap();
+    let natal = hd_core::calculate_chart_at(birth).unwrap();
+    let from = Utc.with_ymd_and_hms(2013, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2016, 1, 1, 0, 0, 0).unwrap();
+    let found = returns(Cycle::SaturnReturn, birth, &natal, from, to).unwrap();
+
+    // Direct, retrograde and direct again around the station
+    assert_eq!(found.iter().map(|r| r.retrograde).collect::<Vec<_>>(), [false, true, false]);
+    for r in &found {
+        assert!(separation(longitude(&r.chart, "Saturn"), longitude(&natal, "Saturn")) < 1e-5, "{}", r.at);
+        assert_eq!(r.analysis.hd_type.name(), hd_core::analyze(&r.chart).unwrap().hd_type.name());
+    }
+}
+
+#[test]
+fn uranus_opposition_is_half_a_circle_away() {
+    let birth = Utc.with_ymd_and_hms(1985, 6, 15, 11, 30, 0).unwrap();
+    let natal = hd_core::calculate_chart_at(birth).unwrap();
+    let (from, to) = hd_core::returns::default_range(Cycle::UranusOpposition, birth, Utc::now());
+    let found = returns(Cycle::UranusOpposition, birth, &natal, from, to).unwrap();
+    assert!(!found.is_empty());
+    for r in &found {
+        assert!((separation(longitude(&r.chart, "Uranus"), longitude(&natal, "Uranus")) - 180.0).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn solar_return_falls_near_birthday() {
+    let birth = Utc.with_ymd_and_hms(1985, 6, 15, 11, 30, 0).unwrap();
+    let natal = hd_core::calculate_chart_at(birth).unwrap();
+    let from = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap();
+    let found = returns(Cycle::SolarReturn, birth, &natal, from, to).unwrap();
+    assert_eq!(found.len(), 1);
+    let r = &found[0];
+    assert!((r.at - Utc.with_ymd_and_hms(2026, 6, 15, 11, 30, 0).unwrap()).abs() < Duration::days(1));
+    let sun = r.chart.personality.iter().find(|a| a.planet == "Sun").unwrap();
+    let natal_sun = natal.personality.iter().find(|a| a.planet == "Sun").unwrap();
+    assert_eq!((sun.gate, sun.line), (natal_sun.gate, natal_sun.line));
+}
+
+#[test]
+fn returns_reject_reversed_range() {
+    let birth = Utc.with_ymd_and_hms(1985, 6, 15, 11, 30, 0).unwrap();
+    let natal = hd_core::calculate_chart_at(birth).unwrap();
+    assert!(matches!(returns(Cycle::SaturnReturn, birth, &natal, birth, birth), Err(HdError::InvalidRange(_))));
+    assert_eq!(Cycle::from_planet("Chiron"), Some(Cycle::ChironReturn));
+    assert_eq!(Cycle::from_planet("mars"), None);
+}
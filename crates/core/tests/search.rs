@@ -0,0 +1,105 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use hd_core::search::{parse_channel, parse_profile};
+use hd_core::{search, ActivationConstraint, HdType, NodeModel, Query, Side};
+
+fn midpoint(from: DateTime<Utc>, to: DateTime<Utc>) -> DateTime<Utc> {
+    from + (to - from) / 2
+}
+
+fn activation<'a>(chart: &'a hd_core::Chart, side: Side, planet: &str) -> &'a hd_core::Activation {
+    let list = match side {
+        Side::Personality => &chart.personality,
+        Side::Design => &chart.design,
+    };
+    list.iter().find(|a| a.planet == planet).unwrap()
+}
+
+#[test]
+fn parses_activation_constraints() {
+    let c = ActivationConstraint::parse("design Moon 41.3").unwrap();
+    assert_eq!((c.planet, c.side, c.gate, c.line), ("Moon", Side::Design, 41, Some(3)));
+    let c = ActivationConstraint::parse("north node 2").unwrap();
+    assert_eq!((c.planet, c.side, c.gate, c.line), ("North Node", Side::Personality, 2, None));
+
+    for bad in ["Sun", "Sun 65", "Sun 13.7", "Vulcan 13", "design 13"] {
+        assert!(ActivationConstraint::parse(bad).is_err(), "{bad}");
+    }
+    assert_eq!(parse_channel("8-1").unwrap(), (1, 8));
+    assert!(parse_channel("1-2").is_err());
+    assert_eq!(parse_profile("6/2").unwrap(), (6, 2));
+    assert!(parse_profile("7/1").is_err());
+}
+
+#[test]
+fn activation_matches_hold_inside_and_stop_at_the_edges() {
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let query = Query {
+        activations: vec![
+            ActivationConstraint::parse("Sun 13").unwrap(),
+            ActivationConstraint::parse("Moon 1").unwrap(),
+        ],
+        ..Query::default()
+    };
+    let matches = search(&query, from, to, NodeModel::True).unwrap();
+    assert!(!matches.is_empty());
+
+    let holds = |at| {
+        let chart = hd_core::calculate_chart_at(at).unwrap();
+        query.activations.iter().all(|c| activation(&chart, c.side, c.planet).gate == c.gate)
+    };
+    for m in &matches {
+        assert!(holds(midpoint(m.from, m.to)));
+        // A second outside the match the constraints no longer hold, unless
+        // the match was clipped to the range
+        if m.from > from {
+            assert!(!holds(m.from - Duration::seconds(1)), "{}", m.from);
+        }
+        if m.to < to {
+            assert!(!holds(m.to + Duration::seconds(1)), "{}", m.to);
+        }
+    }
+}
+
+#[test]
+fn chart_level_constraints_match_the_analysis() {
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 8, 0, 0, 0).unwrap();
+    let query = Query {
+        hd_type: Some(HdType::from_name("projector").unwrap()),
+        profile: Some((6, 2)),
+        ..Query::default()
+    };
+    let matches = search(&query, from, to, NodeModel::True).unwrap();
+    assert!(!matches.is_empty());
+    for m in &matches {
+        let analysis = hd_core::analyze(&hd_core::calculate_chart_at(midpoint(m.from, m.to)).unwrap()).unwrap();
+        assert_eq!(analysis.hd_type.name(), "Projector");
+        assert_eq!(analysis.profile, (6, 2));
+    }
+}
+
+#[test]
+fn empty_query_matches_the_whole_range() {
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+    let to = from + Duration::days(2);
+    let matches = search(&Query::default(), from, to, NodeModel::True).unwrap();
+    assert_eq!(matches.len(), 1);
+    assert_eq!((matches[0].from, matches[0].to), (from, to));
+    assert!(search(&Query::default(), to, from, NodeModel::True).is_err());
+}
+
+#[test]
+fn constraint_order_does_not_change_the_matches() {
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 2, 1, 0, 0, 0).unwrap();
+    let specs = ["Moon 22", "Sun 61", "Mercury 10", "Jupiter 27"];
+    let query = |specs: &[&str]| Query {
+        activations: specs.iter().map(|s| ActivationConstraint::parse(s).unwrap()).collect(),
+        ..Query::default()
+    };
+    let reversed: Vec<&str> = specs.iter().rev().copied().collect();
+    let matches = search(&query(&specs), from, to, NodeModel::True).unwrap();
+    assert!(!matches.is_empty());
+    assert_eq!(matches, search(&query(&reversed), from, to, NodeModel::True).unwrap());
+}
@@ -0,0 +1,63 @@
+use hd_core::gates::{longitude_to_subdivision, BASE_SIZE, COLOR_SIZE, GATE_SIZE, HD_START_DEGREE, LINE_SIZE, TONE_SIZE};
+
+fn parts(lon: f64) -> (u8, u8, u8, u8, u8) {
+    let s = longitude_to_subdivision(lon);
+    (s.gate, s.line, s.color, s.tone, s.base)
+}
+
+#[test]
+fn mandala_start_is_gate_41_first_base() {
+    assert_eq!(parts(HD_START_DEGREE), (41, 1, 1, 1, 1));
+    assert_eq!(longitude_to_subdivision(HD_START_DEGREE).line_position, 0.0);
+}
+
+#[test]
+fn last_base_before_each_boundary() {
+    let eps = 1e-9;
+    assert_eq!(parts(HD_START_DEGREE + BASE_SIZE - eps), (41, 1, 1, 1, 1));
+    assert_eq!(parts(HD_START_DEGREE + BASE_SIZE + eps), (41, 1, 1, 1, 2));
+    assert_eq!(parts(HD_START_DEGREE + TONE_SIZE - eps), (41, 1, 1, 1, 5));
+    assert_eq!(parts(HD_START_DEGREE + TONE_SIZE + eps), (41, 1, 1, 2, 1));
+    assert_eq!(parts(HD_START_DEGREE + COLOR_SIZE - eps), (41, 1, 1, 6, 5));
+    assert_eq!(parts(HD_START_DEGREE + COLOR_SIZE + eps), (41, 1, 2, 1, 1));
+    assert_eq!(parts(HD_START_DEGREE + LINE_SIZE - eps), (41, 1, 6, 6, 5));
+    assert_eq!(parts(HD_START_DEGREE + LINE_SIZE + eps), (41, 2, 1, 1, 1));
+    assert_eq!(parts(HD_START_DEGREE + GATE_SIZE - eps), (41, 6, 6, 6, 5));
+    assert_eq!(parts(HD_START_DEGREE + GATE_SIZE + eps), (19, 1, 1, 1, 1));
+}
+
+#[test]
+fn wraps_through_zero_aries() {
+    // Gate 60 ends and Gate 41 begins at 2°00' Aquarius
+    assert_eq!(parts(HD_START_DEGREE - 1e-9), (60, 6, 6, 6, 5));
+    // 0° Aries falls in Gate 25, line 2 (Gate 25 starts at 28°15' Pisces)
+    let s = longitude_to_subdivision(0.0);
+    assert_eq!((s.gate, s.line), (25, 2));
+    assert!((s.line_position - (1.75 - LINE_SIZE) / LINE_SIZE).abs() < 1e-12);
+    assert_eq!(parts(360.0), parts(0.0));
+}
+
+#[test]
+fn line_position_tracks_offset_within_line() {
+    let lon = HD_START_DEGREE + 3.0 * LINE_SIZE + 0.25 * LINE_SIZE;
+    let s = longitude_to_subdivision(lon);
+    assert_eq!((s.gate, s.line), (41, 4));
+    assert!((s.line_position - 0.25).abs() < 1e-9);
+}
+
+#[test]
+fn chart_activations_carry_consistent_subdivisions() {
+    let chart = hd_core::calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    for a in chart.personality.iter().chain(chart.design.iter()) {
+        let s = longitude_to_subdivision(a.longitude);
+        assert_eq!((a.gate, a.line, a.color, a.tone, a.base), (s.gate, s.line, s.color, s.tone, s.base));
+        assert!((0.0..1.0).contains(&a.line_position));
+    }
+    // Earth sits exactly opposite the Sun: 180° is a whole number of gates,
+    // so line, color, tone and base are shared
+    for side in [&chart.personality, &chart.design] {
+        let sun = side.iter().find(|a| a.planet == "Sun").unwrap();
+        let earth = side.iter().find(|a| a.planet == "Earth").unwrap();
+        assert_eq!((sun.line, sun.color, sun.tone, sun.base), (earth.line, earth.color, earth.tone, earth.base));
+    }
+}
@@ -0,0 +1,110 @@
+use chrono::{Duration, TimeZone, Utc};
+use hd_core::chart::calculate_transit_at;
+use hd_core::ical::ingress_calendar;
+use hd_core::ingress::ingresses;
+use hd_core::transit::{forecast, overlay_transits};
+use hd_core::parse::parse_instant;
+use hd_core::{HdError, NodeModel};
+
+#[test]
+fn parses_absolute_instants() {
+    let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let expected = Utc.with_ymd_and_hms(2026, 12, 21, 10, 0, 0).unwrap();
+    assert_eq!(parse_instant("2026-12-21T10:00Z", now).unwrap(), expected);
+    assert_eq!(parse_instant("2026-12-21T10:00:00Z", now).unwrap(), expected);
+    assert_eq!(parse_instant("2026-12-21 10:00", now).unwrap(), expected);
+    assert_eq!(parse_instant("2026-12-21T12:00+02:00", now).unwrap(), expected);
+    assert_eq!(parse_instant("2026-12-21T04:30-05:30", now).unwrap(), expected);
+    assert_eq!(parse_instant("2026-12-21", now).unwrap(),
+        Utc.with_ymd_and_hms(2026, 12, 21, 0, 0, 0).unwrap());
+}
+
+#[test]
+fn parses_relative_instants() {
+    let now = Utc.with_ymd_and_hms(2026, 12, 21, 10, 0, 0).unwrap();
+    assert_eq!(parse_instant("now", now).unwrap(), now);
+    assert_eq!(parse_instant("+7d", now).unwrap(), Utc.with_ymd_and_hms(2026, 12, 28, 10, 0, 0).unwrap());
+    assert_eq!(parse_instant("-12h", now).unwrap(), Utc.with_ymd_and_hms(2026, 12, 20, 22, 0, 0).unwrap());
+    assert_eq!(parse_instant("+2w", now).unwrap(), Utc.with_ymd_and_hms(2027, 1, 4, 10, 0, 0).unwrap());
+    assert_eq!(parse_instant("+90m", now).unwrap(), Utc.with_ymd_and_hms(2026, 12, 21, 11, 30, 0).unwrap());
+}
+
+#[test]
+fn rejects_malformed_instants() {
+    let now = Utc::now();
+    for s in ["", "tomorrow", "+7", "+7y", "7d", "2026-13-01", "2026-12-21T25:00Z", "2026-12-21T10:00+15"] {
+        assert!(matches!(parse_instant(s, now), Err(HdError::InvalidInstant(_))), "{:?}", s);
+    }
+}
+
+#[test]
+fn transit_at_instant_is_reproducible() {
+    let at = Utc.with_ymd_and_hms(2026, 12, 21, 10, 0, 0).unwrap();
+    let first = calculate_transit_at(at).unwrap();
+    let second = calculate_transit_at(at).unwrap();
+    let sun = first.iter().find(|a| a.planet == "Sun").unwrap();
+    assert_eq!((sun.gate, sun.line), (10, 2));
+    for (a, b) in first.iter().zip(&second) {
+        assert_eq!(a.longitude, b.longitude);
+    }
+}
+
+#[test]
+fn ingresses_land_on_line_boundaries() {
+    let from = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2026, 11, 15, 0, 0, 0).unwrap();
+    let timeline = ingresses(from, to, NodeModel::True).unwrap();
+    assert!(timeline.windows(2).all(|w| w[0].at <= w[1].at));
+    // The Moon changes line roughly every 1.7 hours
+    assert!(timeline.iter().filter(|i| i.planet == "Moon").count() > 150);
+
+    for i in timeline.iter().filter(|i| i.planet != "Moon").take(20) {
+        let find = |at| calculate_transit_at(at).unwrap().into_iter().find(|a| a.planet == i.planet).unwrap();
+        let after = find(i.at + Duration::milliseconds(5));
+        let before = find(i.at - Duration::milliseconds(5));
+        assert_eq!((after.gate, after.line), (i.gate, i.line), "{:?}", i);
+        assert_eq!((before.gate, before.line), (i.from_gate, i.from_line), "{:?}", i);
+    }
+}
+
+#[test]
+fn ingresses_reject_reversed_range() {
+    let at = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+    assert!(matches!(ingresses(at, at, NodeModel::True), Err(HdError::InvalidRange(_))));
+}
+
+#[test]
+fn forecast_windows_agree_with_overlay() {
+    let natal = hd_core::calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap();
+    let analysis = hd_core::analyze(&natal).unwrap();
+    let from = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2026, 12, 1, 0, 0, 0).unwrap();
+    let windows = forecast(&natal.gates(), &analysis.defined_channels, &analysis.defined_centers, from, to, NodeModel::True).unwrap();
+    assert!(!windows.is_empty());
+
+    for w in windows.iter().filter(|w| w.planet != "Moon") {
+        assert!(w.start < w.end && w.start >= from && w.end <= to, "{:?}", w);
+        let mid = w.start + (w.end - w.start) / 2;
+        let positions = calculate_transit_at(mid).unwrap();
+        let completed = overlay_transits(&natal.gates(), &positions, &analysis.defined_channels);
+        assert!(completed.iter().any(|c| (c.gate1, c.gate2) == (w.channel.gate1, w.channel.gate2)), "{:?}", w);
+    }
+}
+
+#[test]
+fn ingress_calendar_is_valid_icalendar() {
+    let from = Utc.with_ymd_and_hms(2026, 11, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2026, 11, 3, 0, 0, 0).unwrap();
+    let timeline = ingresses(from, to, NodeModel::True).unwrap();
+    let ics = ingress_calendar(&timeline, from);
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\nVERSION:2.0\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT\r\n").count(), timeline.len());
+    assert_eq!(ics.matches("END:VEVENT\r\n").count(), timeline.len());
+    for line in ics.split("\r\n") {
+        assert!(line.len() <= 75, "{:?}", line);
+        assert!(!line.contains('\n'));
+    }
+    assert!(ics.contains("SUMMARY:Moon enters Gate 31.1 Influence\r\n"));
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+use hd_core::{analyze_gates, Center, Circuit, Definition, GateAnalysis, SubCircuit};
+
+fn gates(list: &[u8]) -> GateAnalysis {
+    analyze_gates(&list.iter().copied().collect::<HashSet<u8>>())
+}
+
+fn has_group(analysis: &GateAnalysis, centers: &[Center]) -> bool {
+    analysis.definition_groups.iter().any(|g| {
+        let mut g = g.clone();
+        g.sort();
+        let mut centers = centers.to_vec();
+        centers.sort();
+        g == centers
+    })
+}
+
+#[test]
+fn no_channels_means_no_definition() {
+    let analysis = gates(&[1, 2, 3]);
+    assert_eq!(analysis.definition, Definition::None);
+    assert!(analysis.definition_groups.is_empty() && analysis.bridging_gates.is_empty());
+    assert_eq!(analysis.hd_type.name(), "Reflector");
+}
+
+#[test]
+fn one_connected_area_is_single_definition() {
+    // Inspiration (G-Throat) and The Prodigal (G-Throat) share the G center
+    let analysis = gates(&[1, 8, 13, 33]);
+    assert_eq!(analysis.definition, Definition::Single);
+    assert!(has_group(&analysis, &[Center::G, Center::Throat]));
+    assert!(analysis.bridging_gates.is_empty());
+}
+
+#[test]
+fn separate_areas_are_counted() {
+    let split = gates(&[1, 8, 6, 59]);
+    assert_eq!(split.definition, Definition::Split);
+    assert!(has_group(&split, &[Center::G, Center::Throat]));
+    assert!(has_group(&split, &[Center::Sacral, Center::SolarPlexus]));
+
+    let triple = gates(&[1, 8, 6, 59, 24, 61]);
+    assert_eq!(triple.definition, Definition::TripleSplit);
+    assert!(has_group(&triple, &[Center::Head, Center::Ajna]));
+
+    let quadruple = gates(&[1, 8, 6, 59, 24, 61, 18, 58]);
+    assert_eq!(quadruple.definition, Definition::QuadrupleSplit);
+    assert_eq!(quadruple.definition_groups.len(), 4);
+    assert!(has_group(&quadruple, &[Center::Spleen, Center::Root]));
+    assert_eq!(quadruple.definition.name(), "Quadruple Split Definition");
+}
+
+#[test]
+fn hanging_gate_between_areas_names_its_bridge() {
+    // Gate 14 hangs from the Sacral; gate 2 would join it to the G center
+    let analysis = gates(&[1, 8, 6, 59, 14]);
+    assert_eq!(analysis.definition, Definition::Split);
+    assert_eq!(analysis.bridging_gates.len(), 1);
+    let bridge = &analysis.bridging_gates[0];
+    assert_eq!((bridge.gate, bridge.hanging_gate, bridge.channel_name), (2, 14, "The Beat"));
+    assert_eq!(bridge.joins, (Center::G, Center::Sacral));
+
+    // A hanging gate inside one area bridges nothing
+    assert!(gates(&[1, 8, 13]).bridging_gates.is_empty());
+}
+
+fn circuit_counts(analysis: &GateAnalysis) -> [usize; 4] {
+    Circuit::ALL.map(|c| analysis.circuitry.circuits[&c])
+}
+
+#[test]
+fn individual_channels_count_by_sub_circuit() {
+    // Inspiration (Knowing) and Initiation (Centering)
+    let circuitry = gates(&[1, 8, 25, 51]).circuitry;
+    assert_eq!(circuitry.circuits[&Circuit::Individual], 2);
+    assert_eq!((circuitry.sub_circuits[&SubCircuit::Knowing], circuitry.sub_circuits[&SubCircuit::Centering]), (1, 1));
+    assert_eq!(circuitry.dominant, [Circuit::Individual]);
+}
+
+#[test]
+fn collective_channels_count_by_sub_circuit() {
+    // Logic (Logic), Curiosity and Abstraction (Sensing)
+    let analysis = gates(&[4, 63, 11, 56, 47, 64]);
+    assert_eq!(circuit_counts(&analysis), [0, 3, 0, 0]);
+    let sub = &analysis.circuitry.sub_circuits;
+    assert_eq!((sub[&SubCircuit::Logic], sub[&SubCircuit::Sensing]), (1, 2));
+    assert_eq!(analysis.circuitry.dominant, [Circuit::Collective]);
+}
+
+#[test]
+fn tribal_channels_count_by_sub_circuit() {
+    // Money Line (Ego) and Intimacy (Defense)
+    let analysis = gates(&[21, 45, 6, 59]);
+    assert_eq!(circuit_counts(&analysis), [0, 0, 2, 0]);
+    let sub = &analysis.circuitry.sub_circuits;
+    assert_eq!((sub[&SubCircuit::Ego], sub[&SubCircuit::Defense]), (1, 1));
+    assert_eq!(analysis.circuitry.dominant, [Circuit::Tribal]);
+}
+
+#[test]
+fn integration_channels_have_no_sub_circuit() {
+    // Gates 10, 20, 34 and 57 form all six Integration channels
+    let analysis = gates(&[10, 20, 34, 57]);
+    assert_eq!(circuit_counts(&analysis), [0, 0, 0, 6]);
+    assert!(analysis.circuitry.sub_circuits.values().all(|&n| n == 0));
+    assert_eq!(analysis.circuitry.dominant, [Circuit::Integration]);
+}
+
+/// Exploration (10-34) belongs to the Integration group, not to Centering
+#[test]
+fn exploration_counts_as_integration() {
+    let analysis = gates(&[10, 34]);
+    let channel = &analysis.defined_channels[0];
+    assert_eq!((channel.name, channel.circuit, channel.sub_circuit), ("Exploration", Circuit::Integration, None));
+    assert_eq!(circuit_counts(&analysis), [0, 0, 0, 1]);
+    assert_eq!(analysis.circuitry.sub_circuits[&SubCircuit::Centering], 0);
+}
+
+#[test]
+fn ties_name_every_dominant_circuit() {
+    assert_eq!(gates(&[1, 8, 4, 63]).circuitry.dominant, [Circuit::Individual, Circuit::Collective]);
+    assert!(gates(&[]).circuitry.dominant.is_empty());
+}
+
+#[test]
+fn hanging_gates_count_by_circuit_group() {
+    // Inspiration is defined; 25, 41, 59 and 10 hang alone
+    let circuitry = gates(&[1, 8, 25, 41, 59, 10]).circuitry;
+    let hanging = Circuit::ALL.map(|c| circuitry.hanging_gates[&c]);
+    assert_eq!(hanging, [1, 1, 1, 1]);
+    // Gates of a defined channel do not hang
+    assert_eq!(gates(&[10, 34]).circuitry.hanging_gates[&Circuit::Integration], 0);
+}
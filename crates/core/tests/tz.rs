@@ -0,0 +1,48 @@
+use hd_core::{DstPolicy, HdError, ResolvedOffset, Zone};
+
+fn helsinki() -> Zone {
+    Zone::parse("Europe/Helsinki").unwrap()
+}
+
+#[test]
+fn parses_offsets_and_zone_names() {
+    assert_eq!(Zone::parse("+5.5").unwrap(), Zone::Fixed(5.5));
+    assert_eq!(Zone::parse("-05:30").unwrap(), Zone::Fixed(-5.5));
+    assert_eq!(helsinki(), Zone::Named(chrono_tz::Europe::Helsinki));
+    assert!(matches!(Zone::parse("Mars/Olympus"), Err(HdError::InvalidTimezone(_))));
+
+    let fixed = Zone::parse("+5.5").unwrap().resolve(2024, 10, 27, 3.5, DstPolicy::Reject).unwrap();
+    assert_eq!(fixed, ResolvedOffset { utc_offset: 5.5, ambiguous: false, nonexistent: false });
+}
+
+#[test]
+fn summer_and_winter_offsets_come_from_the_zone() {
+    let summer = helsinki().resolve(2024, 6, 15, 12.0, DstPolicy::Reject).unwrap();
+    assert_eq!(summer, ResolvedOffset { utc_offset: 3.0, ambiguous: false, nonexistent: false });
+    let winter = helsinki().resolve(2024, 1, 15, 12.0, DstPolicy::Reject).unwrap();
+    assert_eq!(winter.utc_offset, 2.0);
+}
+
+/// 2024-10-27 03:30 happened twice in Helsinki, at UTC+3 and then UTC+2
+#[test]
+fn repeated_autumn_hour_follows_the_policy() {
+    let err = helsinki().resolve(2024, 10, 27, 3.5, DstPolicy::Reject).unwrap_err();
+    assert!(matches!(&err, HdError::AmbiguousLocalTime(s) if s.contains("UTC+3 or UTC+2")), "{err}");
+
+    let earlier = helsinki().resolve(2024, 10, 27, 3.5, DstPolicy::Earlier).unwrap();
+    assert_eq!(earlier, ResolvedOffset { utc_offset: 3.0, ambiguous: true, nonexistent: false });
+    let later = helsinki().resolve(2024, 10, 27, 3.5, DstPolicy::Later).unwrap();
+    assert_eq!(later, ResolvedOffset { utc_offset: 2.0, ambiguous: true, nonexistent: false });
+}
+
+/// 2024-03-31 03:30 never happened in Helsinki; clocks went from 03:00 to 04:00
+#[test]
+fn skipped_spring_hour_follows_the_policy() {
+    let err = helsinki().resolve(2024, 3, 31, 3.5, DstPolicy::Reject).unwrap_err();
+    assert!(matches!(&err, HdError::NonexistentLocalTime(s) if s.contains("UTC+3 or UTC+2")), "{err}");
+
+    let earlier = helsinki().resolve(2024, 3, 31, 3.5, DstPolicy::Earlier).unwrap();
+    assert_eq!(earlier, ResolvedOffset { utc_offset: 3.0, ambiguous: false, nonexistent: true });
+    let later = helsinki().resolve(2024, 3, 31, 3.5, DstPolicy::Later).unwrap();
+    assert_eq!(later, ResolvedOffset { utc_offset: 2.0, ambiguous: false, nonexistent: true });
+}
@@ -0,0 +1,53 @@
+use hd_core::variables::variables;
+use hd_core::{calculate_chart, Activation, Arrow, Chart};
+
+/// Set the color and tone of an activation of a real chart
+fn set(list: &mut [Activation], planet: &str, color: u8, tone: u8) {
+    let a = list.iter_mut().find(|a| a.planet == planet).unwrap();
+    a.color = color;
+    a.tone = tone;
+}
+
+fn chart() -> Chart {
+    calculate_chart(1985, 6, 15, 14.5, 3.0).unwrap()
+}
+
+#[test]
+fn tones_set_the_arrows_and_colors_name_the_variables() {
+    let mut chart = chart();
+    set(&mut chart.design, "Sun", 1, 1);
+    set(&mut chart.design, "North Node", 2, 6);
+    set(&mut chart.personality, "Sun", 5, 3);
+    set(&mut chart.personality, "North Node", 6, 4);
+    let v = variables(&chart).unwrap();
+
+    assert_eq!((v.determination.arrow, v.determination.name, v.determination.orientation), (Arrow::Left, "Appetite", "Active"));
+    assert_eq!(v.determination.refinement, Some("Consecutive"));
+    assert_eq!((v.environment.arrow, v.environment.name, v.environment.orientation), (Arrow::Right, "Markets", "Observer"));
+    assert_eq!(v.environment.refinement, Some("External"));
+    assert_eq!((v.motivation.arrow, v.motivation.name, v.motivation.orientation), (Arrow::Left, "Guilt", "Strategic"));
+    assert_eq!(v.motivation.refinement, None);
+    assert_eq!((v.perspective.arrow, v.perspective.name, v.perspective.orientation), (Arrow::Right, "Personal", "Peripheral"));
+    assert_eq!((v.perspective.color, v.perspective.tone), (6, 4));
+    assert_eq!(v.notation(), "PLR DLR");
+}
+
+#[test]
+fn arrow_turns_between_tones_three_and_four() {
+    for tone in 1..=6 {
+        let expected = if tone <= 3 { Arrow::Left } else { Arrow::Right };
+        assert_eq!(Arrow::from_tone(tone), expected, "tone {tone}");
+
+        let mut chart = chart();
+        for list in [&mut chart.design, &mut chart.personality] {
+            set(list, "Sun", 3, tone);
+            set(list, "North Node", 4, tone);
+        }
+        let v = variables(&chart).unwrap();
+        for variable in [&v.determination, &v.environment, &v.motivation, &v.perspective] {
+            assert_eq!(variable.arrow, expected, "{} tone {}", variable.source, tone);
+        }
+        assert_eq!([v.determination.name, v.environment.name, v.motivation.name, v.perspective.name],
+            ["Thirst", "Mountains", "Desire", "Wanting"]);
+    }
+}